@@ -1,8 +1,8 @@
-use std::{collections::{BTreeMap, HashSet}, rc::Rc};
+use std::{collections::HashSet, rc::Rc};
 
 use dioxus::prelude::*;
 
-use crate::{create_id, Optional};
+use crate::{create_id, presence::{use_content_size, use_presence}, roving_focus::RovingFocus, Optional, OptionalMaybeSignal};
 
 use super::Orientation;
 
@@ -30,128 +30,74 @@ struct AccordianState {
     pub typ: AccordianType,
     pub orientation: Orientation,
 
-    items: Vec<Rc<MountedData>>,
-    item_map: BTreeMap<String, usize>,
+    focus: RovingFocus,
 
-    current: HashSet<String>,
+    current: Signal<HashSet<String>>,
     onchange: Option<EventHandler<HashSet<String>>>,
 }
 
 impl AccordianState {
-    fn new<S: std::fmt::Display, I: IntoIterator<Item=S>>(
+    fn new(
         collapsible: Option<bool>,
         typ: AccordianType,
         orientation: Option<Orientation>,
-        default: Option<I>,
+        current: Signal<HashSet<String>>,
         onchange: Option<EventHandler<HashSet<String>>>,
     ) -> Self {
-
-        let current: HashSet<String> = if typ.is_multiple() {
-            default.map(|d| d.into_iter().map(|v| v.to_string()).collect()).unwrap_or_default()
-        } else {
-            default.map(|d| d.into_iter().take(1).map(|v| v.to_string()).collect()).unwrap_or_default()
-        };
-
         Self {
             orientation: orientation.unwrap_or(Orientation::Vertical),
             collapsible: collapsible.unwrap_or_default(),
-            items: Default::default(),
-            item_map: Default::default(),
+            focus: RovingFocus::new(Some(orientation.unwrap_or(Orientation::Vertical)), false),
             typ,
             current,
             onchange,
         }
     }
 
+    /// Limit the open items based on the accordian type
+    fn limit<S: std::fmt::Display, I: IntoIterator<Item=S>>(typ: AccordianType, values: I) -> HashSet<String> {
+        if typ.is_multiple() {
+            values.into_iter().map(|v| v.to_string()).collect()
+        } else {
+            values.into_iter().take(1).map(|v| v.to_string()).collect()
+        }
+    }
+
+    /// Replace the open items without notifying `onchange`
+    fn sync(&mut self, values: HashSet<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.current.peek() != values {
+            self.current.set(values);
+        }
+    }
+
     /// Toggle an item's open state
     pub fn toggle(&mut self, new: impl AsRef<str>) {
         let new = new.as_ref();
-        if self.contains(&new) && ((self.typ.is_single() && self.collapsible) || self.typ.is_multiple()) {
-            self.current.remove(new);
+        if self.contains(new) && ((self.typ.is_single() && self.collapsible) || self.typ.is_multiple()) {
+            self.current.write().remove(new);
             if let Some(onchange) = self.onchange.as_ref() {
-                onchange.call(self.current.clone());
+                onchange.call(self.current.peek().clone());
             }
         } else {
-            if !self.typ.is_multiple() && !self.current.is_empty() {
-                self.current.clear();
+            if !self.typ.is_multiple() && !self.current.peek().is_empty() {
+                self.current.write().clear();
             }
 
-            self.current.insert(new.to_string());
+            self.current.write().insert(new.to_string());
             if let Some(onchange) = self.onchange.as_ref() {
-                onchange.call(self.current.clone());
+                onchange.call(self.current.peek().clone());
             }
         }
     }
 
     /// Check if an item's value is currently in the open state
     pub fn contains(&self, value: impl AsRef<str>) -> bool {
-        self.current.contains(value.as_ref())
+        self.current.read().contains(value.as_ref())
     }
 
     fn add_item(&mut self, key: impl AsRef<str>, data: Rc<MountedData>) {
-        let key = key.as_ref();
-        if !self.item_map.contains_key(key) {
-            self.item_map.insert(key.to_string(), self.items.len());
-            self.items.push(data);
-        }
-    }
-
-    async fn handle_key(&self, key: impl AsRef<str>, evt: Event<KeyboardData>) {
-        let key = key.as_ref();
-
-        // TODO: Handle set_focus result response
-
-        match evt.key() {
-            Key::ArrowUp => if self.orientation.is_vertical() {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index > 0 {
-                        self.items[index-1].set_focus(true).await;
-                    }
-                }
-            },
-            Key::ArrowDown => if self.orientation.is_vertical() {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index < self.items.len() - 1 {
-                        self.items[index+1].set_focus(true).await;
-                    }
-                }
-            },
-            Key::ArrowRight => if self.orientation.is_horizontal() {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index < self.items.len() - 1 {
-                        self.items[index+1].set_focus(true).await;
-                    }
-                }
-            },
-            Key::ArrowLeft => if self.orientation.is_horizontal() {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index > 0 {
-                        self.items[index-1].set_focus(true).await;
-                    }
-                }
-            },
-            Key::Home => {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index != 0 {
-                        self.items[0].set_focus(true).await;
-                    }
-                }
-            },
-            Key::End => {
-                evt.prevent_default();
-                if let Some(index) = self.item_map.get(key) {
-                    if *index != self.items.len() - 1 {
-                        self.items[self.items.len() - 1].set_focus(true).await;
-                    }
-                }
-            },
-            _ => {}
-        }
+        self.focus.add_item(key, data);
     }
 }
 
@@ -165,6 +111,11 @@ impl AccordianState {
 /// - `[data-orientation]`: `"vertical"` | `"horizontal"`
 #[component]
 pub fn Accordian(
+    /// Item(s) that are open
+    /// 
+    /// When a signal is passed it is kept in sync with the open items
+    #[props(into, default)]
+    value: OptionalMaybeSignal<HashSet<String>>,
     /// Default item(s) to open
     #[props(into, default)]
     default: Optional<Vec<&'static str>>,
@@ -181,7 +132,14 @@ pub fn Accordian(
 
     children: Element
 ) -> Element {
-    use_context_provider(|| Signal::new(AccordianState::new(collapsible, r#type, orientation, default.as_option(), onchange)));
+    let current = value.clone().as_signal(AccordianState::limit(r#type, default.unwrap_or_default()));
+    let mut state = use_context_provider(|| Signal::new(AccordianState::new(collapsible, r#type, orientation, current, onchange)));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
 
     rsx! {
         div {
@@ -359,7 +317,13 @@ pub fn AccordianTrigger(
             "data-orientation": state.read().orientation,
 
             onclick: move |_: Event<MouseData>| state.write().toggle(iid.read().value()),
-            onkeydown: move |evt| async move { state.read().handle_key(iid.read().value(), evt).await },
+            onkeydown: move |evt| async move {
+                let target = state.read().focus.navigate(iid.read().value(), &evt);
+                if let Some((_, data)) = target {
+                    // TODO: Handle set_focus result response
+                    let _ = data.set_focus(true).await;
+                }
+            },
 
             onmounted: move |v: Event<MountedData>| state.write().add_item(iid.read().value(), v.data()),
 
@@ -372,6 +336,13 @@ pub fn AccordianTrigger(
 
 /// Contains the collapsible content for an item
 /// 
+/// The content stays mounted until its exit animations have finished after the item closes.
+/// 
+/// # CSS Variables
+/// 
+/// - `--meek-accordian-content-width`: The width of the content when it opened
+/// - `--meek-accordian-content-height`: The height of the content when it opened
+/// 
 /// # Data Attributes
 /// 
 /// - `[data-state]`: `"open"` | `"closed"`
@@ -381,6 +352,10 @@ pub fn AccordianTrigger(
 pub fn AccordianContent(
     #[props(into)]
     id: Option<String>,
+    /// Keep the content mounted even when the item is closed
+    /// 
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
     /// Remaining attributes to add to wrapping `div` tag
     #[props(extends = GlobalAttributes)]
     attrs: Vec<Attribute>,
@@ -396,20 +371,37 @@ pub fn AccordianContent(
         }
     });
 
+    let content_id = use_memo(move || iid.read().content_id());
+    let open = use_memo(move || state.read().contains(iid.read().value()));
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || open()));
+    let is_present = use_presence(content_id, present);
+
+    let mut attrs = attrs;
+    let mut content = use_content_size(
+        ("--meek-accordian-content-width", "--meek-accordian-content-height"),
+        open,
+        is_present,
+        &mut attrs,
+    );
+
     rsx! {
         div {
             id: iid.read().content_id(),
             role: if state.read().typ.is_multiple() { None } else { Some("region") },
             aria_labelledby: iid.read().trigger_id(),
 
-            hidden: !state.read().contains(iid.read().value()),
+            hidden: !is_present(),
 
-            "data-state": if state.read().contains(iid.read().value()) { "open" } else { "closed" },
+            "data-state": if open() { "open" } else { "closed" },
             "data-orientation": state.read().orientation,
 
+            onmounted: move |v: Event<MountedData>| content.set(Some(v.data())),
+
             ..attrs,
 
-            {children}
+            if is_present() {
+                {children}
+            }
         }
     }
 }
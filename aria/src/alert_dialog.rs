@@ -1,66 +1,13 @@
 use dioxus::prelude::*;
 
-use crate::{create_id, OptionalMaybeSignal};
-
-struct AlertDialogContext {
-    pub(crate) id: String,
-    pub(crate) title_id: Option<String>,
-    pub(crate) description_id: Option<String>,
-
-    pub(crate) open: Signal<bool>,
-    pub(crate) onchange: Option<EventHandler<bool>>,
-}
-
-impl AlertDialogContext {
-    fn new(id: Option<String>, open: Signal<bool>, onchange: Option<EventHandler<bool>>) -> Self {
-        Self {
-            id: id.unwrap_or(create_id()),
-            title_id: None,
-            description_id: None,
-
-            open,
-            onchange,
-        }
-    }
-
-    fn set_title(&mut self, id: Option<String>) {
-        self.title_id.replace(id.unwrap_or(format!("{}:title", self.id)));
-    }
-
-    fn set_description(&mut self, id: Option<String>) {
-        self.description_id.replace(id.unwrap_or(format!("{}:description", self.id)));
-    }
-
-    fn close(&mut self) {
-        *self.open.write() = false;
-        if let Some(onchange) = self.onchange.as_ref() {
-            onchange.call(false);
-        }
-    }
-
-    fn open(&mut self) {
-        *self.open.write() = true;
-        if let Some(onchange) = self.onchange.as_ref() {
-            onchange.call(true);
-        }
-    }
-
-    fn show(&self) {
-        let id = self.id.clone();
-        spawn(async move {
-            let eval = format!(r#"document.getElementById("{id}")?.showModal()"#);
-            document::eval(eval.as_str()).await.unwrap();
-        });
-    }
-
-    fn hide(&self) {
-        let id = self.id.clone();
-        spawn(async move {
-            let eval = format!(r#"document.getElementById("{id}")?.close()"#);
-            document::eval(eval.as_str()).await.unwrap();
-        });
-    }
-}
+use crate::{
+    dialog::DialogContext,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    focus_scope::use_focus_scope,
+    presence::use_presence,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+};
 
 /// A modal dialog that interrupts the user with important content that expects a response
 #[component]
@@ -78,7 +25,7 @@ pub fn AlertDialog(
     children: Element,
 ) -> Element {
     let mut state = use_signal(|| default.unwrap_or_default());
-    use_context_provider(|| Signal::new(AlertDialogContext::new(id, state, onchange)));
+    use_context_provider(|| Signal::new(DialogContext::new(id, true, state, onchange)));
 
     use_effect(use_reactive!(|open| {
         if let Some(o) = open {
@@ -86,13 +33,6 @@ pub fn AlertDialog(
         }
     }));
 
-    // Update showing and hiding the modal based on the `open` state
-    use_effect(move || {
-        let mut context =  use_context::<Signal<AlertDialogContext>>();
-        if *state.read() { context.write().show() }
-        else { context.write().hide() }
-    });
-
     rsx! {
         {children}
     }
@@ -119,7 +59,7 @@ pub fn AlertDialogTrigger(
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
     rsx! {
         button {
             r#type: "button",
@@ -139,6 +79,8 @@ pub fn AlertDialogTrigger(
 
 /// A `dialog` html element which contains the content to be rendered when it is open
 /// 
+/// The dialog stays open until its exit animations have finished after it is closed.
+/// 
 /// # Data Attributes
 /// 
 /// - `[data-state]`: `"open"` | `"closed"`
@@ -148,19 +90,59 @@ pub fn AlertDialogContent(
     aria_labelledby: Option<String>,
     #[props(into)]
     aria_describedby: Option<String>,
+    /// Keep the content mounted even when the dialog is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when focus moves into the dialog after opening
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    onopenautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when focus moves back to the trigger after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the dialog open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+
     #[props(extends = GlobalAttributes)]
     attrs: Vec<Attribute>,
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
 
-    let onkeydown = move |evt: Event<KeyboardData>| {
-        match evt.key() {
-            Key::Escape => context.write().close(),
-            _ => {}
-        }
-    };
+    let open = use_memo(move || *context.read().open.read());
+    let trapped = use_memo(|| true);
+    use_focus_scope(context.peek().id.clone(), open, trapped, trapped, onopenautofocus, oncloseautofocus);
+
+    // Keep the modal showing until its exit animations have finished
+    let id = use_memo(move || context.read().id.clone());
+    let is_present = use_presence(id, open);
+
+    // Update showing and hiding the modal based on the `open` state
+    use_effect(move || {
+        if open() { context.peek().show(true) }
+        else if !is_present() { context.peek().hide() }
+    });
+
+    // Close the dialog when the user presses escape, interacting outside of it should not close it
+    let ondismiss = use_callback(move |_| context.write().close());
+    let oninteractoutside = use_callback(|evt: DismissableLayerEvent| evt.prevent_default());
+    use_dismissable_layer(
+        context.peek().id.clone(),
+        open,
+        Vec::new(),
+        DismissableLayerHandlers {
+            onescapekeydown,
+            oninteractoutside: Some(oninteractoutside),
+            ondismiss: Some(ondismiss),
+            ..Default::default()
+        },
+    );
 
     rsx! {
         dialog {
@@ -170,11 +152,11 @@ pub fn AlertDialogContent(
             aria_describedby: aria_describedby.as_deref().or(context.read().description_id.as_deref()),
             "data-state": if *context.read().open.read() { "open" } else { "closed" },
 
-            onkeydown: onkeydown,
-
             ..attrs,
 
-            {children}
+            if is_present() || force_mount.unwrap_or_default() {
+                {children}
+            }
         }
     }
 }
@@ -191,7 +173,7 @@ pub fn AlertDialogTitle(
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
 
     use_effect(move || {
         context.write().set_title(id.clone());
@@ -218,7 +200,7 @@ pub fn AlertDialogDescription(
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
 
     use_effect(move || {
         context.write().set_description(id.clone());
@@ -252,7 +234,7 @@ pub fn AlertDialogCancel(
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
 
     rsx! {
         button {
@@ -287,7 +269,7 @@ pub fn AlertDialogAction(
 
     children: Element,
 ) -> Element {
-    let mut context =  use_context::<Signal<AlertDialogContext>>();
+    let mut context =  use_context::<Signal<DialogContext>>();
 
     rsx! {
         button {
@@ -0,0 +1,524 @@
+use std::{cell::Cell, collections::HashMap, rc::Rc};
+
+use dioxus::{html::input_data::MouseButton, prelude::*};
+
+use crate::{
+    create_id,
+    dom_order,
+    DropdownMenu,
+    DropdownMenuContent,
+    DropdownMenuItem,
+    DropdownMenuPortal,
+    DropdownMenuTrigger,
+    MenuSelectEvent,
+    Optional,
+};
+
+/// Selector matching the items and separators of a breadcrumb
+const PARTS: &str = "[data-meek-breadcrumb-item], [data-meek-breadcrumb-separator]";
+
+/// Handles the contextual state of a breadcrumb
+#[derive(Debug)]
+struct BreadcrumbState {
+    id: String,
+    pub max_items: Option<usize>,
+    /// Content of the button that shows the collapsed items
+    ellipsis: Element,
+
+    /// Ids of the items in the order they are rendered
+    items: Vec<String>,
+    /// Id of the item each separator follows, by the id of the separator
+    separators: HashMap<String, Option<String>>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+    /// Content of the collapsed items by their id, rendered in the ellipsis menu
+    collapsed: HashMap<String, Element>,
+}
+
+impl BreadcrumbState {
+    /// The items sorted by the position of their elements in `order`, and the item that precedes each separator
+    fn ordered(&self, order: &[String]) -> (Vec<String>, HashMap<String, Option<String>>) {
+        let mut items = Vec::new();
+        let mut separators = self.separators.clone();
+        let mut last = None;
+        for id in order {
+            if self.items.contains(id) {
+                items.push(id.clone());
+                last = Some(id.clone());
+            } else if let Some(after) = separators.get_mut(id) {
+                after.clone_from(&last);
+            }
+        }
+        // Keep the items that haven't been rendered yet
+        items.extend(self.items.iter().filter(|item| !order.contains(item)).cloned());
+        (items, separators)
+    }
+
+    /// Range of the items that are collapsed into the ellipsis menu
+    ///
+    /// The first item and the last `max_items - 1` items stay visible.
+    fn collapsed_range(&self) -> std::ops::Range<usize> {
+        match self.max_items {
+            Some(max) if self.items.len() > max => 1..self.items.len() + 1 - max.max(2),
+            _ => 0..0,
+        }
+    }
+
+    /// Check if an item is collapsed into the ellipsis menu
+    fn is_collapsed(&self, id: &str) -> bool {
+        self.items.iter().position(|item| item == id).is_some_and(|index| self.collapsed_range().contains(&index))
+    }
+
+    /// Whether the item renders the ellipsis menu in place of the collapsed items
+    fn is_ellipsis(&self, id: &str) -> bool {
+        let range = self.collapsed_range();
+        !range.is_empty() && self.items.get(range.start).is_some_and(|first| first == id)
+    }
+}
+
+/// Breadcrumb
+///
+/// Shows the location of the current page in the hierarchy of the site as a list of links.
+///
+/// When `max_items` is exceeded, the items between the first one and the last ones are collapsed into a menu.
+///
+/// # Accessibility
+///
+/// The `nav` tag is labelled `"Breadcrumb"`, pass an `aria_label` to change it.
+#[component]
+pub fn Breadcrumb(
+    /// Number of items to show before the items in the middle are collapsed into a menu
+    max_items: Option<usize>,
+    /// Content of the button that shows the collapsed items. Defaults to `…`
+    #[props(default = VNode::empty())]
+    ellipsis: Element,
+    /// User defined ID of the breadcrumb
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the breadcrumb `nav` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context_provider(|| Signal::new(BreadcrumbState {
+        id: id.unwrap_or(create_id()),
+        max_items,
+        ellipsis: ellipsis.clone(),
+        items: Vec::new(),
+        separators: HashMap::new(),
+        ordering: false,
+        collapsed: HashMap::new(),
+    }));
+
+    use_effect(use_reactive!(|max_items, ellipsis| {
+        let mut state = state.write();
+        state.max_items = max_items;
+        state.ellipsis = ellipsis;
+    }));
+
+    rsx! {
+        nav {
+            id: state.read().id.as_str(),
+            aria_label: "Breadcrumb",
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The ordered list of items
+#[component]
+pub fn BreadcrumbList(
+    /// Remaining attributes to add to the list `ol` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    rsx! {
+        ol {
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Sort the items in the order they are rendered and find the item that precedes each separator
+///
+/// Parts that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<BreadcrumbState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.id.clone()
+        };
+        let order = dom_order(&id, PARTS).await;
+        let (items, separators) = state.peek().ordered(&order);
+        let changed = {
+            let state = state.peek();
+            items != state.items || separators != state.separators
+        };
+        if changed {
+            let mut state = state.write();
+            state.items = items;
+            state.separators = separators;
+        }
+    });
+}
+
+/// An item in the breadcrumb, holding a `BreadcrumbLink` or `BreadcrumbPage`
+///
+/// Collapsed items stay in the list as hidden `li` tags.
+///
+/// # Data Attributes
+///
+/// - `[data-meek-breadcrumb-ellipsis]`: Present on the item that holds the menu of collapsed items
+#[component]
+pub fn BreadcrumbItem(
+    /// Remaining attributes to add to the item `li` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<BreadcrumbState>>();
+    let id = use_hook(create_id);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            state.write().items.push(id.clone());
+            schedule_order(state);
+        }
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.items.retain(|item| *item != id);
+                state.collapsed.remove(&id);
+            }
+        }
+    });
+
+    let collapsed = state.read().is_collapsed(&id);
+    let ellipsis = state.read().is_ellipsis(&id);
+
+    use_effect({
+        let id = id.clone();
+        use_reactive!(|collapsed, children| {
+            let mut state = state.write();
+            if collapsed {
+                state.collapsed.insert(id.clone(), children);
+            } else {
+                state.collapsed.remove(&id);
+            }
+        })
+    });
+
+    if ellipsis {
+        return render_ellipsis(state, &id);
+    }
+    if collapsed {
+        // Kept in the DOM so that the position of the item is known
+        return rsx! {
+            li {
+                id: id.as_str(),
+                hidden: true,
+                "data-meek-breadcrumb-item": true,
+            }
+        };
+    }
+
+    rsx! {
+        li {
+            id: id.as_str(),
+            "data-meek-breadcrumb-item": true,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Render the menu that holds the content of the collapsed items in place of the item `id`
+fn render_ellipsis(state: Signal<BreadcrumbState>, id: &str) -> Element {
+    let breadcrumb = state.read();
+    let entries = breadcrumb.items.iter()
+        .filter_map(|id| Some((id.clone(), breadcrumb.collapsed.get(id)?.clone())))
+        .collect::<Vec<_>>();
+    let ellipsis = breadcrumb.ellipsis.clone();
+
+    rsx! {
+        li {
+            id: id,
+            "data-meek-breadcrumb-item": true,
+            "data-meek-breadcrumb-ellipsis": true,
+
+            DropdownMenu {
+                DropdownMenuTrigger {
+                    aria_label: "Show more breadcrumbs",
+                    if ellipsis != VNode::empty() {
+                        {ellipsis}
+                    } else {
+                        "…"
+                    }
+                }
+                DropdownMenuPortal {
+                    DropdownMenuContent {
+                        for (id, children) in entries {
+                            {
+                                // Clicks on the link inside of the item already follow it
+                                let clicked = Rc::new(Cell::new(false));
+                                rsx! {
+                                    DropdownMenuItem {
+                                        key: "{id}",
+                                        // Selecting the item with the keyboard follows the link inside of it
+                                        onselect: {
+                                            let id = id.clone();
+                                            let clicked = clicked.clone();
+                                            move |_: MenuSelectEvent| {
+                                                if !clicked.replace(false) {
+                                                    document::eval(&format!(
+                                                        "document.getElementById({:?})?.querySelector('a')?.click()",
+                                                        format!("{id}-collapsed"),
+                                                    ));
+                                                }
+                                            }
+                                        },
+                                        span {
+                                            id: "{id}-collapsed",
+                                            onclick: move |_| clicked.set(true),
+                                            {children}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A link to one of the ancestors of the current page
+///
+/// Internal targets navigate client side when the link is inside of a dioxus `Router`, use an `href` instead when
+/// there is no router.
+///
+/// # Data Attributes
+///
+/// - `[data-current]`: Present when the target is the current route
+#[component]
+pub fn BreadcrumbLink(
+    /// Route or url to navigate to
+    #[props(into, default)]
+    to: Optional<NavigationTarget>,
+
+    /// Remaining attributes to add to the link `a` tag
+    #[props(extends = GlobalAttributes, extends = a)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let router = try_use_context::<RouterContext>();
+    let to = to.as_option();
+    let href = to.as_ref().map(|to| {
+        match to {
+            NavigationTarget::Internal(url) => router.and_then(|router| router.prefix()).unwrap_or_default() + url,
+            NavigationTarget::External(url) => url.clone(),
+        }
+    });
+    let current = match (&to, router) {
+        (Some(NavigationTarget::Internal(url)), Some(router)) => *url == router.full_route_string(),
+        _ => false,
+    };
+
+    rsx! {
+        a {
+            href: href,
+            aria_current: if current { Some("page") } else { None },
+            "data-current": current,
+
+            onclick: move |evt: Event<MouseData>| {
+                let (Some(router), Some(to)) = (router, to.clone()) else {
+                    return;
+                };
+                // Leave modified clicks and external links to the browser
+                let primary = evt.trigger_button() == Some(MouseButton::Primary);
+                if !primary || !evt.modifiers().is_empty() || matches!(to, NavigationTarget::External(_)) {
+                    return;
+                }
+                evt.prevent_default();
+                router.push(to);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The current page, the last item of the breadcrumb
+#[component]
+pub fn BreadcrumbPage(
+    /// Remaining attributes to add to the page `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    rsx! {
+        span {
+            role: "link",
+            aria_disabled: true,
+            aria_current: "page",
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Separates the items of the breadcrumb. Defaults to `/`
+///
+/// Separators that follow a collapsed item are hidden along with it.
+#[component]
+pub fn BreadcrumbSeparator(
+    /// Remaining attributes to add to the separator `li` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<BreadcrumbState>>();
+    let id = use_hook(create_id);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            state.write().separators.insert(id.clone(), None);
+            schedule_order(state);
+        }
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.separators.remove(&id);
+            }
+        }
+    });
+
+    let hidden = {
+        let state = state.read();
+        state.separators.get(&id).cloned().flatten().is_some_and(|after| {
+            state.is_collapsed(&after) && !state.is_ellipsis(&after)
+        })
+    };
+    if hidden {
+        // Kept in the DOM so that the item it follows is known
+        return rsx! {
+            li {
+                id: id.as_str(),
+                hidden: true,
+                "data-meek-breadcrumb-separator": true,
+            }
+        };
+    }
+
+    rsx! {
+        li {
+            id: id.as_str(),
+            role: "presentation",
+            aria_hidden: true,
+            "data-meek-breadcrumb-separator": true,
+
+            ..attrs,
+
+            if children != VNode::empty() {
+                {children}
+            } else {
+                "/"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A breadcrumb with the given number of items, `item-0`, `item-1`..., that shows at most `max_items`
+    fn state(len: usize, max_items: Option<usize>) -> BreadcrumbState {
+        BreadcrumbState {
+            id: "breadcrumb".into(),
+            max_items,
+            ellipsis: VNode::empty(),
+            items: (0..len).map(|index| format!("item-{index}")).collect(),
+            separators: HashMap::new(),
+            ordering: false,
+            collapsed: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn collapsed_range_is_empty_within_max_items() {
+        assert_eq!(state(5, None).collapsed_range(), 0..0);
+        assert_eq!(state(5, Some(5)).collapsed_range(), 0..0);
+        assert_eq!(state(5, Some(8)).collapsed_range(), 0..0);
+    }
+
+    #[test]
+    fn collapsed_range_keeps_first_and_last_items() {
+        // The first item and the last `max_items - 1` items are shown next to the ellipsis
+        assert_eq!(state(6, Some(4)).collapsed_range(), 1..3);
+        assert_eq!(state(6, Some(3)).collapsed_range(), 1..4);
+    }
+
+    #[test]
+    fn collapsed_range_always_shows_first_and_last_item() {
+        assert_eq!(state(6, Some(2)).collapsed_range(), 1..5);
+        assert_eq!(state(6, Some(1)).collapsed_range(), 1..5);
+        assert_eq!(state(6, Some(0)).collapsed_range(), 1..5);
+        assert_eq!(state(2, Some(1)).collapsed_range(), 1..1);
+    }
+
+    #[test]
+    fn first_collapsed_item_renders_ellipsis() {
+        let state = state(6, Some(4));
+        assert!(!state.is_collapsed("item-0"));
+        assert!(state.is_collapsed("item-1"));
+        assert!(state.is_collapsed("item-2"));
+        assert!(!state.is_collapsed("item-3"));
+        assert!(state.is_ellipsis("item-1"));
+        assert!(!state.is_ellipsis("item-2"));
+    }
+
+    #[test]
+    fn ordered_moves_separators_after_preceding_item() {
+        let mut state = state(2, None);
+        state.items.reverse();
+        state.separators.insert("separator".into(), Some("item-1".into()));
+        let order = ["item-0", "separator", "item-1"].map(String::from);
+        let (items, separators) = state.ordered(&order);
+        assert_eq!(items, ["item-0", "item-1"].map(String::from));
+        assert_eq!(separators["separator"], Some("item-0".into()));
+    }
+}
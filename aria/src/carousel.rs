@@ -0,0 +1,644 @@
+use dioxus::prelude::*;
+
+use crate::{create_id, dom_order, presence::use_presence, roving_focus::RovingFocus, sleep, OptionalMaybeSignal, Orientation};
+
+/// Selector matching the slides of a carousel
+const SLIDES: &str = "[data-meek-carousel-slide]";
+
+/// Handles the contextual state of a carousel
+#[derive(Debug)]
+struct CarouselState {
+    id: String,
+    pub orientation: Orientation,
+    pub looped: bool,
+    /// Milliseconds each slide is shown for while rotating
+    pub interval: u64,
+
+    /// Ids of the slides in the order they are rendered
+    slides: Vec<String>,
+    /// Whether the slides are about to be sorted in the order they are rendered
+    ordering: bool,
+    /// Whether a `CarouselPicker` is rendered, which makes the slides tab panels
+    tabbed: bool,
+    picker: RovingFocus,
+
+    /// Whether the slides rotate automatically, as set with the rotation control
+    playing: bool,
+    /// Rotation is paused while the pointer is over the carousel
+    hovered: bool,
+    /// Rotation is paused while focus is inside of the carousel
+    focused: bool,
+
+    current: Signal<usize>,
+    onchange: Option<EventHandler<usize>>,
+}
+
+impl CarouselState {
+    /// Get the formatted viewport id
+    fn viewport_id(&self) -> String {
+        format!("meek-carousel-viewport-{}", self.id)
+    }
+
+    /// Get the formatted picker tab id for a slide
+    fn tab_id(&self, slide: &str) -> String {
+        format!("meek-carousel-tab-{}-{slide}", self.id)
+    }
+
+    /// Position of the slide in the carousel
+    fn index(&self, slide: &str) -> Option<usize> {
+        self.slides.iter().position(|current| current == slide)
+    }
+
+    /// Check if a slide is the one that is shown
+    pub fn is_active(&self, slide: &str) -> bool {
+        self.index(slide) == Some(*self.current.read())
+    }
+
+    /// Whether the slides are currently rotating, taking the pauses into account
+    pub fn is_rotating(&self) -> bool {
+        self.playing && !self.hovered && !self.focused
+    }
+
+    /// Index of the slide next to the current one, `None` when the edge is reached and `wrap` is off
+    fn step(&self, forward: bool, wrap: bool) -> Option<usize> {
+        let len = self.slides.len();
+        let current = *self.current.read();
+        match (forward, current) {
+            _ if len == 0 => None,
+            (true, c) if c + 1 < len => Some(c + 1),
+            (true, _) if wrap => Some(0),
+            (false, 0) if wrap => Some(len - 1),
+            (false, c) if c > 0 => Some((c - 1).min(len - 1)),
+            _ => None,
+        }
+    }
+
+    /// Show the slide at the given index
+    pub fn select(&mut self, index: usize) {
+        if *self.current.peek() != index {
+            self.current.set(index);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(index);
+            }
+        }
+    }
+
+    /// Show the next slide
+    pub fn next(&mut self) {
+        if let Some(index) = self.step(true, self.looped) {
+            self.select(index);
+        }
+    }
+
+    /// Show the previous slide
+    pub fn previous(&mut self) {
+        if let Some(index) = self.step(false, self.looped) {
+            self.select(index);
+        }
+    }
+}
+
+/// Carousel
+///
+/// Presents a set of slides one at a time, optionally rotating through them automatically.
+///
+/// Without a `CarouselPicker` the slides are labelled groups, navigated with `CarouselPrevious` and `CarouselNext`.
+/// With a picker the slides become tab panels that are chosen from its list of tabs.
+///
+/// Automatic rotation pauses while the pointer is over the carousel or focus is inside of it.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"playing"` | `"paused"`, whether the slides are currently rotating
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+///
+/// # Accessibility
+///
+/// The `section` tag is described as a `"carousel"`, pass an `aria_label` to name it.
+#[component]
+pub fn Carousel(
+    /// Index of the shown slide
+    ///
+    /// When a signal is passed it is kept in sync with the shown slide
+    #[props(into, default)]
+    value: OptionalMaybeSignal<usize>,
+    /// Index of the slide that is shown by default
+    default: Option<usize>,
+    /// Handler that is run when the shown slide changes
+    onchange: Option<EventHandler<usize>>,
+    /// Whether the slides start rotating automatically
+    autoplay: Option<bool>,
+    /// Milliseconds each slide is shown for while rotating. Defaults to `5000`
+    interval: Option<u64>,
+    /// Whether the previous and next buttons wrap around from the last slide to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+    /// Direction of the slides. Defaults to `horizontal`
+    orientation: Option<Orientation>,
+
+    /// User defined ID of the carousel
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the carousel `section` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut current = value.clone().as_signal(default.unwrap_or_default());
+    let orientation = orientation.unwrap_or(Orientation::Horizontal);
+    let mut state = use_context_provider(|| Signal::new(CarouselState {
+        id: id.unwrap_or(create_id()),
+        orientation,
+        looped: r#loop.unwrap_or(true),
+        interval: interval.unwrap_or(5000),
+        slides: Vec::new(),
+        ordering: false,
+        tabbed: false,
+        picker: RovingFocus::new(Some(orientation), true),
+        playing: autoplay.unwrap_or_default(),
+        hovered: false,
+        focused: false,
+        current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            if *current.peek() != value {
+                current.set(value);
+            }
+        }
+    }));
+
+    use_effect(use_reactive!(|orientation, r#loop, interval| {
+        let mut state = state.write();
+        state.orientation = orientation;
+        state.picker.orientation = Some(orientation);
+        state.looped = r#loop.unwrap_or(true);
+        state.interval = interval.unwrap_or(5000);
+    }));
+
+    // Every slide is shown for the full interval, also after navigating manually
+    let rotating = use_memo(move || state.read().is_rotating());
+    let mut timer = use_signal(|| None::<Task>);
+    use_effect(move || {
+        let _ = current();
+        if let Some(task) = timer.write().take() {
+            task.cancel();
+        }
+        if rotating() {
+            let interval = state.peek().interval;
+            timer.set(Some(spawn(async move {
+                sleep(interval).await;
+                let mut state = state.write();
+                // Automatic rotation always starts over after the last slide
+                if let Some(index) = state.step(true, true) {
+                    state.select(index);
+                }
+            })));
+        }
+    });
+
+    rsx! {
+        section {
+            aria_roledescription: "carousel",
+
+            "data-state": if rotating() { "playing" } else { "paused" },
+            "data-orientation": orientation,
+
+            onpointerenter: move |_| state.write().hovered = true,
+            onpointerleave: move |_| state.write().hovered = false,
+            onfocusin: move |_| state.write().focused = true,
+            onfocusout: move |_| state.write().focused = false,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Contains the slides and announces the shown slide to assistive technologies
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+///
+/// # Accessibility
+///
+/// The viewport is a live region that is `off` while the slides rotate automatically and `polite` otherwise, so
+/// only slides that the user navigated to are announced.
+#[component]
+pub fn CarouselViewport(
+    /// Remaining attributes to add to the viewport `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<CarouselState>>();
+    let carousel = state.read();
+
+    rsx! {
+        div {
+            id: carousel.viewport_id(),
+            aria_live: if carousel.is_rotating() { "off" } else { "polite" },
+            aria_atomic: false,
+
+            "data-orientation": carousel.orientation,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Sort the slides in the order they are rendered, which can differ from the order they mounted in
+///
+/// Slides that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<CarouselState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let viewport = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.viewport_id()
+        };
+        let order = dom_order(&viewport, SLIDES).await;
+        let mut slides = state.peek().slides.clone();
+        slides.sort_by_key(|slide| order.iter().position(|id| id == slide).unwrap_or(usize::MAX));
+        if slides != state.peek().slides {
+            let mut state = state.write();
+            state.picker.reorder(&slides);
+            state.slides = slides;
+        }
+    });
+}
+
+/// A slide in the `CarouselViewport`
+///
+/// The slide stays mounted until its exit animations have finished after another slide is shown.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"active"` | `"inactive"`
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+///
+/// # Accessibility
+///
+/// Slides are described as a `"slide"` and labelled with their position, like `"1 of 4"`. Pass an `aria_label` to
+/// name them instead.
+#[component]
+pub fn CarouselSlide(
+    /// Keep the slide mounted even when it isn't shown
+    ///
+    /// Useful when all slides are laid out next to each other, or when controlling animations with a third party
+    /// library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the slide `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<CarouselState>>();
+    let id = use_hook(create_id);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            state.write().slides.push(id.clone());
+            schedule_order(state);
+        }
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.slides.retain(|slide| *slide != id);
+                state.picker.remove_item(&id);
+            }
+        }
+    });
+
+    let slide_id = use_memo({
+        let id = id.clone();
+        move || id.clone()
+    });
+    let active = use_memo(move || state.read().is_active(&slide_id.read()));
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || active()));
+    let is_present = use_presence(slide_id, present);
+
+    let carousel = state.read();
+    let position = carousel.index(&id).map(|index| index + 1).unwrap_or_default();
+
+    rsx! {
+        div {
+            id: id.clone(),
+            role: if carousel.tabbed { "tabpanel" } else { "group" },
+            aria_roledescription: "slide",
+            aria_label: "{position} of {carousel.slides.len()}",
+
+            hidden: !is_present(),
+
+            "data-meek-carousel-slide": true,
+            "data-state": if active() { "active" } else { "inactive" },
+            "data-orientation": carousel.orientation,
+
+            ..attrs,
+
+            if is_present() {
+                {children}
+            }
+        }
+    }
+}
+
+/// Render a button that moves to a slide next to the current one
+fn render_step(mut state: Signal<CarouselState>, forward: bool, attrs: Vec<Attribute>, children: Element) -> Element {
+    let carousel = state.read();
+    let disabled = carousel.step(forward, carousel.looped).is_none();
+
+    rsx! {
+        button {
+            r#type: "button",
+            aria_controls: carousel.viewport_id(),
+            aria_label: if forward { "Next slide" } else { "Previous slide" },
+            disabled: disabled,
+
+            "data-orientation": carousel.orientation,
+            "data-disabled": disabled,
+
+            onclick: move |_| {
+                let mut state = state.write();
+                if forward { state.next() } else { state.previous() }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The button that shows the previous slide
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when the first slide is shown and the carousel doesn't loop
+#[component]
+pub fn CarouselPrevious(
+    /// Remaining attributes to add to the button `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<CarouselState>>();
+    render_step(state, false, attrs, children)
+}
+
+/// The button that shows the next slide
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when the last slide is shown and the carousel doesn't loop
+#[component]
+pub fn CarouselNext(
+    /// Remaining attributes to add to the button `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<CarouselState>>();
+    render_step(state, true, attrs, children)
+}
+
+/// A list of tabs, one for each slide, to choose the shown slide from
+///
+/// Rendering a picker turns the carousel into a tabbed carousel. The tabs are empty buttons that can be styled as
+/// dots with their data attributes.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+///
+/// Each tab has:
+///
+/// - `[data-meek-carousel-tab]`: Always present
+/// - `[data-state]`: `"active"` | `"inactive"`
+///
+/// # Accessibility
+///
+/// The tabs are labelled `"Slide n"`.
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the tab of the shown slide.
+/// - `<ArrowRight>`: [Horizontal] Shows the next slide and focuses its tab.
+/// - `<ArrowLeft>`: [Horizontal] Shows the previous slide and focuses its tab.
+/// - `<ArrowDown>`: [Vertical] Shows the next slide and focuses its tab.
+/// - `<ArrowUp>`: [Vertical] Shows the previous slide and focuses its tab.
+/// - `<Home>`: Shows the first slide and focuses its tab.
+/// - `<End>`: Shows the last slide and focuses its tab.
+#[component]
+pub fn CarouselPicker(
+    /// Remaining attributes to add to the picker `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+) -> Element {
+    let mut state = use_context::<Signal<CarouselState>>();
+    use_effect(move || state.write().tabbed = true);
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.tabbed = false;
+        }
+    });
+
+    let carousel = state.read();
+    let current = *carousel.current.read();
+    let slides = carousel.slides.clone();
+
+    rsx! {
+        div {
+            role: "tablist",
+            aria_label: "Slides",
+            aria_orientation: carousel.orientation,
+            "data-orientation": carousel.orientation,
+
+            ..attrs,
+
+            for (index, slide) in slides.into_iter().enumerate() {
+                button {
+                    key: "{slide}",
+                    r#type: "button",
+                    role: "tab",
+                    id: carousel.tab_id(&slide),
+                    aria_selected: index == current,
+                    aria_controls: slide.clone(),
+                    aria_label: "Slide {index + 1}",
+                    tabindex: if index == current { 0 } else { -1 },
+
+                    "data-meek-carousel-tab": true,
+                    "data-state": if index == current { "active" } else { "inactive" },
+
+                    onclick: move |_| state.write().select(index),
+                    onkeydown: {
+                        let slide = slide.clone();
+                        move |evt| {
+                            let slide = slide.clone();
+                            async move {
+                                let target = state.read().picker.navigate(&slide, &evt);
+                                if let Some((key, data)) = target {
+                                    let index = state.read().index(&key);
+                                    if let Some(index) = index {
+                                        state.write().select(index);
+                                    }
+                                    let _ = data.set_focus(true).await;
+                                }
+                            }
+                        }
+                    },
+                    onmounted: {
+                        let slide = slide.clone();
+                        move |v: Event<MountedData>| state.write().picker.add_item(&slide, v.data())
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// The button that starts and stops the automatic rotation of the slides
+///
+/// Render it as the first focusable element of the carousel. Focusing it doesn't pause the rotation, so the
+/// rotation can be started from the keyboard.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"playing"` | `"paused"`, whether automatic rotation is turned on
+///
+/// # Accessibility
+///
+/// The button is labelled `"Stop automatic slide show"` or `"Start automatic slide show"` depending on its state.
+#[component]
+pub fn CarouselRotationControl(
+    /// Remaining attributes to add to the control `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<CarouselState>>();
+    let playing = state.read().playing;
+
+    rsx! {
+        button {
+            r#type: "button",
+            aria_controls: state.read().viewport_id(),
+            aria_label: if playing { "Stop automatic slide show" } else { "Start automatic slide show" },
+
+            "data-state": if playing { "playing" } else { "paused" },
+
+            onclick: move |_| {
+                let mut state = state.write();
+                state.playing = !state.playing;
+            },
+            // Keep the carousel from pausing while the control has focus
+            onfocusin: move |evt| evt.stop_propagation(),
+            onfocusout: move |evt| evt.stop_propagation(),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `test` with a carousel state of `len` slides that shows the slide at `current`
+    fn with_state(len: usize, current: usize, test: impl FnOnce(&mut CarouselState)) {
+        let dom = VirtualDom::prebuilt(VNode::empty);
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| {
+            let mut state = CarouselState {
+                id: create_id(),
+                orientation: Orientation::Horizontal,
+                looped: false,
+                interval: 5000,
+                slides: (0..len).map(|index| format!("slide-{index}")).collect(),
+                ordering: false,
+                tabbed: false,
+                picker: RovingFocus::new(Some(Orientation::Horizontal), true),
+                playing: false,
+                hovered: false,
+                focused: false,
+                current: Signal::new(current),
+                onchange: None,
+            };
+            test(&mut state);
+        }));
+    }
+
+    #[test]
+    fn step_moves_between_slides() {
+        with_state(3, 1, |state| {
+            assert_eq!(state.step(true, false), Some(2));
+            assert_eq!(state.step(false, false), Some(0));
+        });
+    }
+
+    #[test]
+    fn step_stops_at_edges_without_wrapping() {
+        with_state(3, 2, |state| assert_eq!(state.step(true, false), None));
+        with_state(3, 0, |state| assert_eq!(state.step(false, false), None));
+    }
+
+    #[test]
+    fn step_wraps_around() {
+        with_state(3, 2, |state| assert_eq!(state.step(true, true), Some(0)));
+        with_state(3, 0, |state| assert_eq!(state.step(false, true), Some(2)));
+    }
+
+    #[test]
+    fn step_without_slides_finds_nothing() {
+        with_state(0, 0, |state| {
+            assert_eq!(state.step(true, true), None);
+            assert_eq!(state.step(false, true), None);
+        });
+    }
+
+    #[test]
+    fn step_recovers_when_slides_were_removed() {
+        with_state(2, 4, |state| {
+            assert_eq!(state.step(false, false), Some(1));
+            assert_eq!(state.step(true, false), None);
+            assert_eq!(state.step(true, true), Some(0));
+        });
+    }
+
+    #[test]
+    fn next_and_previous_follow_looping() {
+        with_state(2, 1, |state| {
+            state.next();
+            assert_eq!(*state.current.peek(), 1);
+            state.looped = true;
+            state.next();
+            assert_eq!(*state.current.peek(), 0);
+            state.previous();
+            assert_eq!(*state.current.peek(), 1);
+        });
+    }
+}
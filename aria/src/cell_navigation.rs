@@ -0,0 +1,102 @@
+use dioxus::prelude::*;
+
+/// Selectors of the rows and cells of a grid like widget, like `Grid` and `Treegrid`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CellLayout {
+    /// Selector matching the rows
+    pub rows: &'static str,
+    /// Selector matching the cells inside of a row
+    pub cells: &'static str,
+    /// Whether the rows receive focus themselves, moving left from the first cell then focuses the row
+    pub row_focus: bool,
+}
+
+/// Row or cell to move focus to, relative to the focused row or cell
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CellTarget {
+    NextRow,
+    PreviousRow,
+    /// The row the given number of rows down, or the last row
+    NextPage(usize),
+    /// The row the given number of rows up, or the first row
+    PreviousPage(usize),
+    FirstRow,
+    LastRow,
+    /// The closest row above with a lower `aria-level`
+    ParentRow,
+    NextCell,
+    PreviousCell,
+    FirstCell,
+    LastCell,
+    FirstInGrid,
+    LastInGrid,
+}
+
+impl CellTarget {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::NextRow => "nextRow",
+            Self::PreviousRow => "previousRow",
+            Self::NextPage(_) => "nextPage",
+            Self::PreviousPage(_) => "previousPage",
+            Self::FirstRow => "firstRow",
+            Self::LastRow => "lastRow",
+            Self::ParentRow => "parentRow",
+            Self::NextCell => "nextCell",
+            Self::PreviousCell => "previousCell",
+            Self::FirstCell => "firstCell",
+            Self::LastCell => "lastCell",
+            Self::FirstInGrid => "firstInGrid",
+            Self::LastInGrid => "lastInGrid",
+        }
+    }
+}
+
+/// Find the row or cell of the grid at the target position relative to the row or cell `current`
+///
+/// Moving between rows while a cell has focus keeps the column. Only rendered rows are taken into account, so a
+/// virtualized grid has to render the rows around the focused one. Returns the id of the row or cell.
+pub(crate) async fn find_cell(grid: &str, current: &str, layout: CellLayout, target: CellTarget) -> Option<String> {
+    let CellLayout { rows, cells, row_focus } = layout;
+    let page = match target {
+        CellTarget::NextPage(page) | CellTarget::PreviousPage(page) => page,
+        _ => 0,
+    };
+    let target = target.as_str();
+    let mut eval = document::eval(&format!(r#"
+        const grid = document.getElementById({grid:?});
+        const current = document.getElementById({current:?});
+        if (!grid || !current) {{
+            dioxus.send(null);
+            return;
+        }}
+
+        const rows = [...grid.querySelectorAll(`{rows}`)];
+        const row = current.closest(`{rows}`);
+        const index = rows.indexOf(row);
+        const cells = (row) => row ? [...row.querySelectorAll(`{cells}`)] : [];
+        // -1 when the row itself has focus
+        const column = cells(row).indexOf(current);
+        const inColumn = (row) => !row || column < 0 ? row : cells(row)[column] ?? cells(row).at(-1);
+        const level = (row) => Number(row.getAttribute("aria-level"));
+
+        const target = {{
+            nextRow: () => inColumn(rows[index + 1]),
+            previousRow: () => inColumn(rows[index - 1]),
+            nextPage: () => inColumn(rows[Math.min(index + {page}, rows.length - 1)]),
+            previousPage: () => inColumn(rows[Math.max(index - {page}, 0)]),
+            firstRow: () => inColumn(rows[0]),
+            lastRow: () => inColumn(rows.at(-1)),
+            parentRow: () => rows.slice(0, index).reverse().find((other) => level(other) < level(row)),
+            nextCell: () => cells(row)[column + 1],
+            previousCell: () => column > 0 ? cells(row)[column - 1] : {row_focus} && column === 0 ? row : null,
+            firstCell: () => cells(row)[0],
+            lastCell: () => cells(row).at(-1),
+            firstInGrid: () => cells(rows[0])[0],
+            lastInGrid: () => cells(rows.at(-1)).at(-1),
+        }}["{target}"]();
+        dioxus.send(target && target !== current ? target.id : null);
+    "#));
+
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
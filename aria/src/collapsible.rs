@@ -0,0 +1,201 @@
+use dioxus::prelude::*;
+
+use crate::{create_id, presence::{use_content_size, use_presence}};
+
+/// Handles the contextual state of a collapsible
+#[derive(Debug)]
+struct CollapsibleState {
+    id: String,
+    pub disabled: bool,
+
+    open: Signal<bool>,
+    onchange: Option<EventHandler<bool>>,
+}
+
+impl CollapsibleState {
+    /// Get the formatted trigger id
+    fn trigger_id(&self) -> String {
+        format!("meek-collapsible-trigger-{}", self.id)
+    }
+
+    /// Get the formatted content id
+    fn content_id(&self) -> String {
+        format!("meek-collapsible-content-{}", self.id)
+    }
+
+    fn toggle(&mut self) {
+        let open = !*self.open.peek();
+        self.open.set(open);
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(open);
+        }
+    }
+}
+
+/// Collapsible
+///
+/// A single section of content that is shown and hidden by a button, also known as a disclosure.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn Collapsible(
+    /// Passed state whether the collapsible should be open or closed
+    open: Option<bool>,
+    /// Default open state of the collapsible
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+    /// Whether the collapsible can be opened or closed by the user
+    disabled: Option<bool>,
+
+    /// User defined ID of the collapsible
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the collapsible `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut current = use_signal(|| open.or(default).unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(CollapsibleState {
+        id: id.unwrap_or(create_id()),
+        disabled: disabled.unwrap_or_default(),
+        open: current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            current.set(open);
+        }
+    }));
+
+    use_effect(use_reactive!(|disabled| {
+        state.write().disabled = disabled.unwrap_or_default();
+    }));
+
+    rsx! {
+        div {
+            "data-state": if current() { "open" } else { "closed" },
+            "data-disabled": state.read().disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The button that toggles the collapsible
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Opens/Closes the collapsible.
+/// - `<Enter>`: Opens/Closes the collapsible.
+#[component]
+pub fn CollapsibleTrigger(
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<CollapsibleState>>();
+    let collapsible = state.read();
+    let open = *collapsible.open.read();
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: collapsible.trigger_id(),
+            aria_controls: collapsible.content_id(),
+            aria_expanded: open,
+            disabled: collapsible.disabled,
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": collapsible.disabled,
+
+            onclick: move |_| {
+                if !state.read().disabled {
+                    state.write().toggle();
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Contains the content that is shown and hidden
+///
+/// The content stays mounted until its exit animations have finished after the collapsible closes.
+///
+/// # CSS Variables
+///
+/// - `--meek-collapsible-content-width`: The width of the content when it opened
+/// - `--meek-collapsible-content-height`: The height of the content when it opened
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn CollapsibleContent(
+    /// Keep the content mounted even when the collapsible is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<CollapsibleState>>();
+
+    let content_id = use_memo(move || state.read().content_id());
+    let open = use_memo(move || *state.read().open.read());
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || open()));
+    let is_present = use_presence(content_id, present);
+
+    let mut attrs = attrs;
+    let mut content = use_content_size(
+        ("--meek-collapsible-content-width", "--meek-collapsible-content-height"),
+        open,
+        is_present,
+        &mut attrs,
+    );
+
+    rsx! {
+        div {
+            id: content_id,
+            hidden: !is_present(),
+
+            "data-state": if open() { "open" } else { "closed" },
+            "data-disabled": state.read().disabled,
+
+            onmounted: move |v: Event<MountedData>| content.set(Some(v.data())),
+
+            ..attrs,
+
+            if is_present() {
+                {children}
+            }
+        }
+    }
+}
@@ -0,0 +1,1216 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    popper::{use_popper, Placement, PopperAnchor, PopperContext, PopperOptions},
+    presence::use_presence,
+    Align,
+    DismissableLayerEvent,
+    Optional,
+    OptionalMaybeSignal,
+    Provider,
+    Side,
+    Teleport,
+};
+
+/// Selector matching the items of a combobox that can be highlighted
+const ITEM_SELECTOR: &str = "[data-meek-combobox-item]:not([data-disabled])";
+
+/// Whether a combobox selects one or many items
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum ComboboxType {
+    #[default]
+    Single,
+    Multiple,
+}
+
+impl<A: AsRef<str>> From<A> for ComboboxType {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "single" => Self::Single,
+            "multiple" => Self::Multiple,
+            other => panic!("unknown combobox type: '{other}'")
+        }
+    }
+}
+
+/// How a combobox helps completing the typed text
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum ComboboxAutocomplete {
+    /// The items are always shown as they are
+    None,
+    /// The items are filtered by the typed text and the first match is highlighted
+    #[default]
+    List,
+    /// The first item that starts with the typed text is highlighted and completes the text in the input
+    Inline,
+    /// The items are filtered and the first match completes the text in the input
+    Both,
+}
+
+impl ComboboxAutocomplete {
+    fn filters(self) -> bool {
+        matches!(self, Self::List | Self::Both)
+    }
+
+    fn completes(self) -> bool {
+        matches!(self, Self::Inline | Self::Both)
+    }
+
+    /// Value of the `aria-autocomplete` attribute
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::List => "list",
+            Self::Inline => "inline",
+            Self::Both => "both",
+        }
+    }
+}
+
+impl<A: AsRef<str>> From<A> for ComboboxAutocomplete {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "none" => Self::None,
+            "list" => Self::List,
+            "inline" => Self::Inline,
+            "both" => Self::Both,
+            other => panic!("unknown combobox autocomplete: '{other}'")
+        }
+    }
+}
+
+/// Which item to highlight when moving through the items with the keyboard
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Highlight {
+    First,
+    Last,
+    /// The first selected item, or the first item when none is selected
+    Selected,
+    Next,
+    Previous,
+}
+
+impl Highlight {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::First => "first",
+            Self::Last => "last",
+            Self::Selected => "selected",
+            Self::Next => "next",
+            Self::Previous => "previous",
+        }
+    }
+}
+
+/// Find the item to highlight in the content with the given `id` once it has rendered
+///
+/// Items are searched in document order, so they may be rendered in any order, e.g. from an async resource.
+/// The item is scrolled into view. Returns its id.
+async fn find_highlight(id: &str, current: Option<&str>, highlight: Highlight) -> Option<String> {
+    let current = current.unwrap_or_default();
+    let highlight = highlight.as_str();
+    let mut eval = document::eval(&format!(r#"
+        requestAnimationFrame(() => {{
+            const content = document.getElementById("{id}");
+            const items = content ? [...content.querySelectorAll(`{ITEM_SELECTOR}`)] : [];
+            const index = items.findIndex((item) => item.id === {current:?});
+            const target = {{
+                first: () => items[0],
+                last: () => items[items.length - 1],
+                selected: () => items.find((item) => item.getAttribute("aria-selected") === "true") ?? items[0],
+                next: () => index < 0 ? items[0] : items[index + 1] ?? items[index],
+                previous: () => index < 0 ? items[items.length - 1] : items[index - 1] ?? items[index],
+            }}["{highlight}"]();
+            target?.scrollIntoView({{ block: "nearest" }});
+            dioxus.send(target?.id ?? null);
+        }});
+    "#));
+
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
+
+/// An item that is rendered in the content
+#[derive(Debug, Clone, PartialEq)]
+struct ComboboxItemEntry {
+    value: String,
+    text: String,
+    disabled: bool,
+}
+
+/// Handles the contextual state of a combobox
+struct ComboboxState {
+    id: String,
+    pub typ: ComboboxType,
+    pub autocomplete: ComboboxAutocomplete,
+    pub name: Option<String>,
+    pub required: bool,
+    pub disabled: bool,
+    pub loading: bool,
+    /// Whether a `ComboboxAnchor` is used instead of the input to position the content
+    pub custom_anchor: bool,
+    filter: Option<Callback<(String, String), bool>>,
+
+    /// Rendered items by their id
+    items: HashMap<String, ComboboxItemEntry>,
+    /// Text of every item that has been rendered by its value
+    ///
+    /// Kept after the items unmount, so selected items keep showing their text while the content is closed
+    texts: HashMap<String, String>,
+    /// Id of the item that is highlighted, which is what `aria-activedescendant` points to
+    highlighted: Option<String>,
+    /// Whether the items are filtered by the query, which stops once an item is selected
+    filtering: bool,
+
+    query: Signal<String>,
+    oninputchange: Option<EventHandler<String>>,
+
+    current: Signal<Vec<String>>,
+    onchange: Option<EventHandler<Vec<String>>>,
+
+    open: Signal<bool>,
+    onopenchange: Option<EventHandler<bool>>,
+}
+
+impl ComboboxState {
+    /// Get the formatted input id
+    fn input_id(&self) -> String {
+        format!("{}:input", self.id)
+    }
+
+    /// Get the formatted trigger id
+    fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    /// Get the formatted id of the element the content is positioned against
+    fn anchor_id(&self) -> String {
+        if self.custom_anchor { format!("{}:anchor", self.id) } else { self.input_id() }
+    }
+
+    /// Get the formatted id of the element that positions the content
+    fn popper_id(&self) -> String {
+        format!("{}:popper", self.id)
+    }
+
+    /// Limit the selected items based on the combobox type
+    fn limit(typ: ComboboxType, values: Vec<String>) -> Vec<String> {
+        if typ.is_multiple() {
+            values
+        } else {
+            values.into_iter().take(1).collect()
+        }
+    }
+
+    /// Replace the selected items without notifying `onchange`
+    fn sync(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.current.peek() != values {
+            self.current.set(values);
+        }
+    }
+
+    /// Check if an item is selected
+    fn is_selected(&self, value: impl AsRef<str>) -> bool {
+        self.current.read().iter().any(|current| current == value.as_ref())
+    }
+
+    /// Text of the item with the given value, or the value itself when the item has never been rendered
+    fn text(&self, value: &str) -> String {
+        self.texts.get(value).cloned().unwrap_or_else(|| value.to_string())
+    }
+
+    /// Whether an item with the given text is shown for the current query
+    fn matches(&self, text: &str) -> bool {
+        let query = self.query.read();
+        if !self.filtering || !self.autocomplete.filters() || query.is_empty() {
+            return true;
+        }
+        match self.filter.as_ref() {
+            Some(filter) => filter.call((text.to_string(), query.clone())),
+            None => text.to_lowercase().contains(&query.to_lowercase()),
+        }
+    }
+
+    /// Whether any item is shown for the current query
+    fn has_matches(&self) -> bool {
+        self.items.values().any(|item| self.matches(&item.text))
+    }
+
+    fn set_query(&mut self, query: String) {
+        if *self.query.peek() != query {
+            self.query.set(query.clone());
+            if let Some(oninputchange) = self.oninputchange.as_ref() {
+                oninputchange.call(query);
+            }
+        }
+    }
+
+    fn notify(&self) {
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(self.current.peek().clone());
+        }
+    }
+
+    /// Select an item, or toggle it when multiple items can be selected
+    fn select(&mut self, value: &str) {
+        self.filtering = false;
+        if self.typ.is_multiple() {
+            if self.is_selected(value) {
+                self.current.write().retain(|current| current != value);
+            } else {
+                self.current.write().push(value.to_string());
+            }
+            self.set_query(String::new());
+        } else {
+            if !self.is_selected(value) {
+                self.current.set(vec![value.to_string()]);
+            }
+            let text = self.text(value);
+            self.set_query(text);
+            self.set_open(false);
+        }
+        self.notify();
+    }
+
+    /// Unselect an item
+    fn remove(&mut self, value: &str) {
+        if self.is_selected(value) {
+            self.current.write().retain(|current| current != value);
+            self.notify();
+        }
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if !open {
+            self.highlighted = None;
+        }
+        if *self.open.peek() != open {
+            self.open.set(open);
+            if let Some(onopenchange) = self.onopenchange.as_ref() {
+                onopenchange.call(open);
+            }
+        }
+    }
+}
+
+/// Combobox
+///
+/// An input that is combined with a list of items to pick from. Typing filters the items or completes the text.
+///
+/// Hidden inputs are rendered so that the selected values are submitted with a form.
+///
+/// Set `loading` while the items are fetched, e.g. from a resource that reads the text passed to `oninputchange`.
+#[component]
+pub fn Combobox(
+    /// Selected item(s)
+    ///
+    /// When a signal is passed it is kept in sync with the selected items
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default item(s) to select
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the selected items change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether one or many items can be selected
+    #[props(into, default)]
+    r#type: ComboboxType,
+
+    /// Handler that is run when the text in the input changes
+    oninputchange: Option<EventHandler<String>>,
+    /// How typing helps picking an item. Defaults to `list`
+    #[props(into, default)]
+    autocomplete: ComboboxAutocomplete,
+    /// Returns whether an item with the given text, the first argument, matches the typed text, the second argument
+    ///
+    /// Defaults to a case insensitive search for the typed text. Return `true` for every item when the items are
+    /// already filtered, e.g. by a server.
+    filter: Option<Callback<(String, String), bool>>,
+    /// Whether the items are being loaded
+    loading: Option<bool>,
+
+    /// Passed state whether the combobox should be open or closed
+    open: Option<bool>,
+    /// Default open state of the combobox
+    default_open: Option<bool>,
+    /// Event handler for when the open state changes
+    onopenchange: Option<EventHandler<bool>>,
+
+    /// Name of the combobox when submitted as part of a form
+    ///
+    /// Comboboxes that select multiple items submit their values as `name[]`
+    #[props(into)]
+    name: Option<String>,
+    /// Whether an item must be selected before the owning form can be submitted
+    required: Option<bool>,
+    /// Whether the combobox is disabled
+    disabled: Option<bool>,
+    /// User defined ID of the combobox content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(ComboboxState::limit(r#type, default.unwrap_or_default()));
+    let query = use_signal(String::new);
+    let mut is_open = use_signal(|| open.or(default_open).unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(ComboboxState {
+        id: id.unwrap_or(create_id()),
+        typ: r#type,
+        autocomplete,
+        name: name.clone(),
+        required: required.unwrap_or_default(),
+        disabled: disabled.unwrap_or_default(),
+        loading: loading.unwrap_or_default(),
+        custom_anchor: false,
+        filter,
+        items: HashMap::new(),
+        texts: HashMap::new(),
+        highlighted: None,
+        filtering: false,
+        query,
+        oninputchange,
+        current,
+        onchange,
+        open: is_open,
+        onopenchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            is_open.set(open);
+        }
+    }));
+
+    use_effect(use_reactive!(|autocomplete, filter, loading, name, required, disabled| {
+        let mut state = state.write();
+        state.autocomplete = autocomplete;
+        state.filter = filter;
+        state.loading = loading.unwrap_or_default();
+        state.name = name;
+        state.required = required.unwrap_or_default();
+        state.disabled = disabled.unwrap_or_default();
+    }));
+
+    // The input shows the text of the selected item, which is only known once the item has been rendered
+    use_effect(move || {
+        let state = state.read();
+        if state.typ.is_single() && !state.filtering {
+            if let Some(value) = state.current.read().first() {
+                let text = state.text(value);
+                let mut query = state.query;
+                if *query.peek() != text {
+                    query.set(text);
+                }
+            }
+        }
+    });
+
+    let combobox = state.read();
+    let name = combobox.name.clone()
+        .map(|name| if combobox.typ.is_multiple() { format!("{name}[]") } else { name });
+
+    rsx! {
+        {children}
+        for value in combobox.current.read().iter() {
+            input {
+                key: "{value}",
+                r#type: "hidden",
+                name: name.clone(),
+                value: value.as_str(),
+                disabled: combobox.disabled,
+            }
+        }
+    }
+}
+
+/// An optional element to position the content against instead of the input
+///
+/// Useful for wrapping the input together with the chips of the selected items.
+#[component]
+pub fn ComboboxAnchor(
+    /// Remaining attributes to add to the anchor `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+
+    use_effect(move || state.write().custom_anchor = true);
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.custom_anchor = false;
+        }
+    });
+
+    rsx! {
+        div {
+            // `anchor_id` points elsewhere until the effect above has run
+            id: format!("{}:anchor", state.read().id),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The text input of the combobox
+///
+/// Focus stays in the input while moving through the items, the highlighted item is referenced with
+/// `aria-activedescendant`.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowDown>`: Opens the combobox and highlights the selected item, or moves the highlight to the next item.
+/// - `<ArrowUp>`: Opens the combobox and highlights the selected item, or moves the highlight to the previous item.
+/// - `<Alt+ArrowDown>`: Opens the combobox without highlighting an item.
+/// - `<Alt+ArrowUp>`: Closes the combobox.
+/// - `<Enter>`: Selects the highlighted item.
+/// - `<Escape>`: Closes the combobox, or clears the input when it is closed.
+/// - `<Backspace>`: Unselects the last item when the input is empty and multiple items can be selected.
+#[component]
+pub fn ComboboxInput(
+    /// Remaining attributes to add to the `input` tag
+    #[props(extends = GlobalAttributes, extends = input)]
+    attrs: Vec<Attribute>,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+
+    let (disabled, open, highlighted) = {
+        let state = state.read();
+        let open = *state.open.read();
+        (state.disabled, open, if open { state.highlighted.clone() } else { None })
+    };
+
+    let highlight = move |current: Option<String>, target: Highlight| async move {
+        let id = state.read().id.clone();
+        let target = find_highlight(&id, current.as_deref(), target).await;
+        state.write().highlighted = target;
+    };
+
+    rsx! {
+        input {
+            r#type: "text",
+            role: "combobox",
+            id: state.read().input_id(),
+            value: state.read().query.read().clone(),
+            autocomplete: "off",
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_autocomplete: state.read().autocomplete.as_str(),
+            aria_haspopup: "listbox",
+            aria_expanded: open,
+            aria_controls: if open { Some(state.read().id.clone()) } else { None },
+            aria_activedescendant: highlighted,
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": disabled,
+
+            oninput: move |evt: Event<FormData>| async move {
+                let text = evt.value();
+                let (id, input, autocomplete, grew) = {
+                    let mut state = state.write();
+                    let previous = state.query.peek().clone();
+                    state.filtering = true;
+                    state.highlighted = None;
+                    state.set_query(text.clone());
+                    state.set_open(true);
+                    // Deleting text should not complete it again
+                    let grew = text.len() > previous.len();
+                    (state.id.clone(), state.input_id(), state.autocomplete, grew)
+                };
+                if autocomplete.is_none() || text.is_empty() {
+                    return;
+                }
+
+                // Wait for the filtered items to render before highlighting the first match
+                let complete = autocomplete.completes() && grew;
+                let mut eval = document::eval(&format!(r#"
+                    requestAnimationFrame(() => {{
+                        const content = document.getElementById("{id}");
+                        const input = document.getElementById("{input}");
+                        const query = {text:?};
+                        const items = content ? [...content.querySelectorAll(`{ITEM_SELECTOR}`)] : [];
+                        const text = (item) => item.dataset.meekTextValue ?? "";
+                        const match = {completes}
+                            ? items.find((item) => text(item).toLowerCase().startsWith(query.toLowerCase()))
+                            : items[0];
+                        if (match && {complete} && input && input.value === query) {{
+                            input.value = query + text(match).slice(query.length);
+                            input.setSelectionRange(query.length, input.value.length);
+                        }}
+                        match?.scrollIntoView({{ block: "nearest" }});
+                        dioxus.send(match?.id ?? null);
+                    }});
+                "#, completes = autocomplete.completes()));
+                let target = eval.recv::<Option<String>>().await.ok().flatten();
+                state.write().highlighted = target;
+            },
+            onkeydown: move |evt: Event<KeyboardData>| async move {
+                if disabled {
+                    return;
+                }
+                let (open, current) = {
+                    let state = state.read();
+                    let open = *state.open.peek();
+                    (open, state.highlighted.clone())
+                };
+                let alt = evt.modifiers().alt();
+                match evt.key() {
+                    Key::ArrowDown | Key::ArrowUp if alt => {
+                        evt.prevent_default();
+                        let open = evt.key() == Key::ArrowDown;
+                        state.write().set_open(open);
+                    }
+                    Key::ArrowDown | Key::ArrowUp => {
+                        evt.prevent_default();
+                        let target = match (open, evt.key() == Key::ArrowDown) {
+                            (false, _) => Highlight::Selected,
+                            (true, true) => Highlight::Next,
+                            (true, false) => Highlight::Previous,
+                        };
+                        state.write().set_open(true);
+                        highlight(current, target).await;
+                    }
+                    Key::Home | Key::End if open && current.is_some() => {
+                        evt.prevent_default();
+                        let target = if evt.key() == Key::Home { Highlight::First } else { Highlight::Last };
+                        highlight(current, target).await;
+                    }
+                    Key::Enter if open => {
+                        let value = current.and_then(|id| {
+                            let state = state.read();
+                            state.items.get(&id).filter(|item| !item.disabled).map(|item| item.value.clone())
+                        });
+                        if let Some(value) = value {
+                            evt.prevent_default();
+                            state.write().select(&value);
+                        }
+                    }
+                    Key::Escape if !open => {
+                        let mut state = state.write();
+                        if !state.query.peek().is_empty() {
+                            evt.prevent_default();
+                            state.filtering = false;
+                            state.set_query(String::new());
+                            // The input shows the selected item, so clearing it unselects the item
+                            if state.typ.is_single() {
+                                let selected = state.current.peek().first().cloned();
+                                if let Some(selected) = selected {
+                                    state.remove(&selected);
+                                }
+                            }
+                        }
+                    }
+                    Key::Backspace => {
+                        let mut state = state.write();
+                        if state.typ.is_multiple() && state.query.peek().is_empty() {
+                            let last = state.current.peek().last().cloned();
+                            if let Some(last) = last {
+                                state.remove(&last);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            },
+
+            ..attrs,
+        }
+    }
+}
+
+/// A button that toggles the combobox
+///
+/// It is skipped when tabbing, since the input already opens the combobox from the keyboard.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ComboboxTrigger(
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+    let disabled = state.read().disabled;
+    let open = *state.read().open.read();
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: state.read().trigger_id(),
+            tabindex: -1,
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_label: if open { "Hide options" } else { "Show options" },
+            aria_expanded: open,
+            aria_controls: state.read().id.as_str(),
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": disabled,
+
+            onclick: move |_| async move {
+                let input = {
+                    let mut state = state.write();
+                    let open = !*state.open.peek();
+                    // Every item is shown when opening with the button
+                    state.filtering = false;
+                    state.set_open(open);
+                    state.input_id()
+                };
+                document::eval(&format!(r#"document.getElementById("{input}")?.focus()"#));
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Shares the value of a chip with `ComboboxChipRemove`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ComboboxChipState {
+    value: Signal<String>,
+}
+
+/// Displays a selected item when multiple items can be selected
+///
+/// Renders the text of the item unless children are passed. Render one for every selected value, usually inside of
+/// `ComboboxAnchor` next to the input.
+///
+/// # Data Attributes
+///
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ComboboxChip(
+    /// The value of the selected item
+    #[props(into)]
+    value: String,
+
+    /// Remaining attributes to add to the chip `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<ComboboxState>>();
+    let mut chip = use_context_provider(|| ComboboxChipState { value: Signal::new(value.clone()) });
+    use_effect(use_reactive!(|value| chip.value.set(value)));
+
+    rsx! {
+        span {
+            "data-disabled": state.read().disabled,
+
+            ..attrs,
+
+            if children != VNode::empty() {
+                {children}
+            } else {
+                {state.read().text(&value)}
+            }
+        }
+    }
+}
+
+/// A button that unselects the item of the chip. Must live inside `ComboboxChip`
+///
+/// It is skipped when tabbing, `<Backspace>` in the empty input unselects the last item instead.
+#[component]
+pub fn ComboboxChipRemove(
+    /// Remaining attributes to add to the remove `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+    let chip = use_context::<ComboboxChipState>();
+    let disabled = state.read().disabled;
+    let text = state.read().text(&chip.value.read());
+
+    rsx! {
+        button {
+            r#type: "button",
+            tabindex: -1,
+            disabled: disabled,
+            aria_label: "Remove {text}",
+
+            // Keep focus in the input
+            onpointerdown: move |evt| evt.prevent_default(),
+            onclick: move |_| {
+                let value = chip.value.peek().clone();
+                state.write().remove(&value);
+            },
+
+            ..attrs,
+
+            if children != VNode::empty() {
+                {children}
+            } else {
+                "×"
+            }
+        }
+    }
+}
+
+/// Teleports the content to where the `Portal` with the same `name` is rendered
+///
+/// Use this to escape `overflow: hidden` and stacking contexts of the parent elements.
+#[component]
+pub fn ComboboxPortal(
+    /// Name of the `Portal` to render the content in
+    #[props(into)]
+    name: Option<String>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<ComboboxState>>();
+
+    rsx! {
+        Teleport {
+            name: name,
+            // Teleported elements lose their context, so it is provided again where they are rendered
+            Provider {
+                inherit: state,
+                {children}
+            }
+        }
+    }
+}
+
+/// The listbox that pops out when the combobox is open
+///
+/// The content is positioned against the input, or `ComboboxAnchor`, and flips and shifts to stay inside of the
+/// collision boundary. It stays mounted until its exit animations have finished after it is closed.
+///
+/// Pressing `<Escape>` or interacting outside of the input and the content closes the combobox.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`
+/// - `[data-loading]`: Present while the items are loading
+///
+/// # CSS Variables
+///
+/// - `--meek-popper-available-width`: The width between the anchor and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the anchor and the edge of the boundary
+/// - `--meek-popper-anchor-width`: The width of the anchor
+/// - `--meek-popper-anchor-height`: The height of the anchor
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the anchor
+#[component]
+pub fn ComboboxContent(
+    /// The side of the anchor to place the content against. Defaults to `bottom`
+    #[props(into, default)]
+    side: Side,
+    /// Distance in pixels between the anchor and the content. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Alignment of the content along the side of the anchor. Defaults to `start`
+    #[props(into, default = Align::Start)]
+    align: Align,
+    /// Offset in pixels from the `align` position. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the opposite side and shifts along the anchor to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+
+    /// Keep the content mounted even when the combobox is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the combobox open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the input and the content
+    ///
+    /// Call `prevent_default` on the event to keep the combobox open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when focus moves outside of the input and the content
+    ///
+    /// Call `prevent_default` on the event to keep the combobox open
+    onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the input and the content
+    ///
+    /// Call `prevent_default` on the event to keep the combobox open
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+
+    let id = use_memo(move || state.read().id.clone());
+    let open = use_memo(move || *state.read().open.read());
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || open()));
+    let is_present = use_presence(id, present);
+
+    // The content may be teleported, so wait until it is in the DOM before managing it
+    let mut mounted = use_signal(|| false);
+    use_effect(move || if !is_present() { mounted.set(false) });
+    let active = use_memo(move || open() && mounted());
+
+    let ondismiss = use_callback(move |_| state.write().set_open(false));
+    let branches = {
+        let state = state.peek();
+        vec![state.anchor_id(), state.input_id(), state.trigger_id()]
+    };
+    use_dismissable_layer(
+        state.peek().id.clone(),
+        active,
+        branches,
+        DismissableLayerHandlers {
+            onescapekeydown,
+            onpointerdownoutside,
+            onfocusoutside,
+            oninteractoutside,
+            ondismiss: Some(ondismiss),
+        },
+    );
+
+    let options = use_memo(use_reactive!(|side, side_offset, align, align_offset, avoid_collisions, collision_padding, collision_boundary| {
+        PopperOptions {
+            side,
+            align,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: 0.0,
+        }
+    }));
+    let anchor = use_memo(move || PopperAnchor::Element(state.read().anchor_id()));
+    let popper = use_memo(move || state.read().popper_id());
+    let tracking = use_memo(move || is_present() && mounted());
+    let placement = use_popper(anchor, popper, tracking, options);
+    use_context_provider(|| PopperContext { placement });
+
+    let placed = *placement.read();
+    let mut attrs = attrs;
+    Placement::push_attributes(placed.as_ref(), &mut attrs);
+
+    let (multiple, loading) = {
+        let state = state.read();
+        (state.typ.is_multiple(), state.loading)
+    };
+
+    rsx! {
+        if is_present() {
+            div {
+                id: state.read().popper_id(),
+                position: "fixed",
+                left: "0px",
+                top: "0px",
+                min_width: "max-content",
+                transform: Placement::transform(placed.as_ref()),
+
+                div {
+                    role: "listbox",
+                    id: state.read().id.as_str(),
+                    aria_multiselectable: multiple,
+                    aria_busy: loading,
+                    "data-state": if open() { "open" } else { "closed" },
+                    "data-side": placed.map(|p| p.side).unwrap_or(side),
+                    "data-align": placed.map(|p| p.align).unwrap_or(align),
+                    "data-loading": loading,
+
+                    // Keep focus in the input
+                    onpointerdown: move |evt| evt.prevent_default(),
+                    onmounted: move |_| mounted.set(true),
+
+                    ..attrs,
+
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/// Shares whether the item that contains a `ComboboxItemIndicator` is selected
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ComboboxItemState {
+    selected: Memo<bool>,
+}
+
+/// An item in the combobox
+///
+/// The item is not rendered when it does not match the text typed in the input.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ComboboxItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` event is run and what is submitted with a form
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for filtering and shown in the input or chip when the item is selected. Defaults to the value
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ComboboxState>>();
+    let disabled = disabled.unwrap_or_default();
+    let text = text_value.unwrap_or_else(|| value.clone());
+
+    let id = use_signal(create_id);
+    let current = use_memo(use_reactive!(|value| value));
+    let selected = use_memo(move || state.read().is_selected(current.read().as_str()));
+    use_context_provider(|| ComboboxItemState { selected });
+
+    use_effect(use_reactive!(|value, text, disabled| {
+        let mut state = state.write();
+        state.texts.insert(value.clone(), text.clone());
+        state.items.insert(id.peek().clone(), ComboboxItemEntry { value, text, disabled });
+    }));
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            let id = id.peek();
+            state.items.remove(id.as_str());
+            if state.highlighted.as_deref() == Some(id.as_str()) {
+                state.highlighted = None;
+            }
+        }
+    });
+
+    if !state.read().matches(&text) {
+        return VNode::empty();
+    }
+
+    let highlighted = state.read().highlighted.as_deref() == Some(id.read().as_str());
+
+    rsx! {
+        div {
+            role: "option",
+            id: id,
+            aria_selected: selected(),
+            aria_disabled: disabled,
+            "data-meek-combobox-item": true,
+            "data-meek-text-value": text.as_str(),
+            "data-state": if selected() { "checked" } else { "unchecked" },
+            "data-highlighted": highlighted,
+            "data-disabled": disabled,
+
+            onpointermove: move |_| {
+                if !disabled && !highlighted {
+                    state.write().highlighted = Some(id.peek().clone());
+                }
+            },
+            onclick: move |_| {
+                if !disabled {
+                    let value = current.peek().clone();
+                    state.write().select(&value);
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Renders when the item is selected. Must live inside `ComboboxItem`
+#[component]
+pub fn ComboboxItemIndicator(
+    /// Remaining attributes to add to the indicator `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let item = use_context::<ComboboxItemState>();
+
+    rsx! {
+        if (item.selected)() {
+            span {
+                aria_hidden: true,
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Renders when no item matches the typed text and the items are not loading
+#[component]
+pub fn ComboboxEmpty(
+    /// Remaining attributes to add to the empty `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<ComboboxState>>();
+    let empty = {
+        let state = state.read();
+        !state.loading && !state.has_matches()
+    };
+
+    rsx! {
+        if empty {
+            div {
+                role: "presentation",
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Renders while the items are loading
+#[component]
+pub fn ComboboxLoading(
+    /// Remaining attributes to add to the loading `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<ComboboxState>>();
+
+    rsx! {
+        if state.read().loading {
+            div {
+                role: "presentation",
+                aria_live: "polite",
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `test` with an open combobox state of the given type that selected `values`
+    fn with_state(typ: ComboboxType, values: Vec<&str>, test: impl FnOnce(&mut ComboboxState)) {
+        let dom = VirtualDom::prebuilt(VNode::empty);
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| {
+            let mut state = ComboboxState {
+                id: create_id(),
+                typ,
+                autocomplete: ComboboxAutocomplete::List,
+                name: None,
+                required: false,
+                disabled: false,
+                loading: false,
+                custom_anchor: false,
+                filter: None,
+                items: HashMap::new(),
+                texts: HashMap::from([("de".to_string(), "Germany".to_string())]),
+                highlighted: Some(create_id()),
+                filtering: true,
+                query: Signal::new("Ger".to_string()),
+                oninputchange: None,
+                current: Signal::new(values.into_iter().map(String::from).collect()),
+                onchange: None,
+                open: Signal::new(true),
+                onopenchange: None,
+            };
+            test(&mut state);
+        }));
+    }
+
+    #[test]
+    fn limit_keeps_first_value_when_single() {
+        let values = vec!["de".to_string(), "fr".to_string()];
+        assert_eq!(ComboboxState::limit(ComboboxType::Single, values.clone()), vec!["de".to_string()]);
+        assert_eq!(ComboboxState::limit(ComboboxType::Multiple, values.clone()), values);
+        assert!(ComboboxState::limit(ComboboxType::Single, Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn select_single_replaces_value_and_closes() {
+        with_state(ComboboxType::Single, vec!["fr"], |state| {
+            state.select("de");
+            assert_eq!(*state.current.peek(), vec!["de".to_string()]);
+            assert_eq!(*state.query.peek(), "Germany");
+            assert!(!*state.open.peek());
+            assert!(state.highlighted.is_none());
+            assert!(!state.filtering);
+
+            // Selecting the selected item again keeps it selected
+            state.select("de");
+            assert_eq!(*state.current.peek(), vec!["de".to_string()]);
+        });
+    }
+
+    #[test]
+    fn select_single_falls_back_to_value_text() {
+        with_state(ComboboxType::Single, vec![], |state| {
+            state.select("fr");
+            assert_eq!(*state.query.peek(), "fr");
+        });
+    }
+
+    #[test]
+    fn select_multiple_toggles_and_stays_open() {
+        with_state(ComboboxType::Multiple, vec!["fr"], |state| {
+            state.select("de");
+            assert_eq!(*state.current.peek(), vec!["fr".to_string(), "de".to_string()]);
+            assert_eq!(*state.query.peek(), "");
+            assert!(*state.open.peek());
+
+            state.select("fr");
+            assert_eq!(*state.current.peek(), vec!["de".to_string()]);
+        });
+    }
+}
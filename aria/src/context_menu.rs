@@ -0,0 +1,295 @@
+use dioxus::prelude::*;
+
+use crate::{
+    dismissable_layer::DismissableLayerHandlers,
+    menu::{render_menu_content, MenuContentOptions, MenuEntry, MenuRootState},
+    popper::{PopperAnchor, PopperOptions},
+    sleep,
+    Align,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+    Side,
+};
+
+pub use crate::menu::{
+    MenuArrow as ContextMenuArrow,
+    MenuArrowProps as ContextMenuArrowProps,
+    MenuCheckboxItem as ContextMenuCheckboxItem,
+    MenuCheckboxItemProps as ContextMenuCheckboxItemProps,
+    MenuGroup as ContextMenuGroup,
+    MenuGroupProps as ContextMenuGroupProps,
+    MenuItem as ContextMenuItem,
+    MenuItemIndicator as ContextMenuItemIndicator,
+    MenuItemIndicatorProps as ContextMenuItemIndicatorProps,
+    MenuItemProps as ContextMenuItemProps,
+    MenuLabel as ContextMenuLabel,
+    MenuLabelProps as ContextMenuLabelProps,
+    MenuPortal as ContextMenuPortal,
+    MenuPortalProps as ContextMenuPortalProps,
+    MenuRadioGroup as ContextMenuRadioGroup,
+    MenuRadioGroupProps as ContextMenuRadioGroupProps,
+    MenuRadioItem as ContextMenuRadioItem,
+    MenuRadioItemProps as ContextMenuRadioItemProps,
+    MenuSeparator as ContextMenuSeparator,
+    MenuSeparatorProps as ContextMenuSeparatorProps,
+    MenuSub as ContextMenuSub,
+    MenuSubContent as ContextMenuSubContent,
+    MenuSubContentProps as ContextMenuSubContentProps,
+    MenuSubProps as ContextMenuSubProps,
+    MenuSubTrigger as ContextMenuSubTrigger,
+    MenuSubTriggerProps as ContextMenuSubTriggerProps,
+};
+
+/// How long a touch has to be held before the menu opens
+const LONG_PRESS_DURATION: u64 = 700;
+
+/// Open the menu with the content placed at the given viewport coordinates
+fn open_at(mut root: Signal<MenuRootState>, point: (f64, f64), entry: MenuEntry) {
+    let mut root = root.write();
+    root.point = Some(point);
+    root.entry = entry;
+    root.set_open(true);
+}
+
+/// Context Menu
+///
+/// Displays a menu located at the pointer, triggered by a right click or a long press.
+#[component]
+pub fn ContextMenu(
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+    /// User defined ID of the menu content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let current = use_signal(|| false);
+    use_context_provider(|| Signal::new(MenuRootState::new(id, current, onchange)));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// The area that opens the context menu
+///
+/// The area itself is not focusable, add a `tabindex` to it or place focusable elements inside of it
+/// to open the menu from the keyboard.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Shift+F10>`: Opens the menu below the focused element and focuses the first item.
+/// - `<ContextMenu>`: Opens the menu below the focused element and focuses the first item.
+#[component]
+pub fn ContextMenuTrigger(
+    /// When `true`, the browser's own context menu is shown instead
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the trigger `span` tag
+    #[props(extends = GlobalAttributes)]
+    mut attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+    // Keep iOS from showing its own callout on long press
+    attrs.push(Attribute::new("-webkit-touch-callout", "none", Some("style"), false));
+    let disabled = disabled.unwrap_or_default();
+    let open = *root.read().open.read();
+
+    let mut long_press = use_signal(|| None::<Task>);
+    let mut cancel_long_press = move || {
+        if let Some(task) = long_press.take() {
+            task.cancel();
+        }
+    };
+
+    rsx! {
+        span {
+            id: root.read().trigger_id(),
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": disabled,
+
+            oncontextmenu: move |evt: Event<MouseData>| {
+                if disabled {
+                    return;
+                }
+                evt.prevent_default();
+                cancel_long_press();
+                let point = evt.client_coordinates();
+                open_at(root, (point.x, point.y), MenuEntry::Pointer);
+            },
+            onpointerdown: move |evt: Event<PointerData>| {
+                if disabled || evt.pointer_type() == "mouse" {
+                    return;
+                }
+                cancel_long_press();
+                let point = evt.client_coordinates();
+                let point = (point.x, point.y);
+                long_press.set(Some(spawn(async move {
+                    sleep(LONG_PRESS_DURATION).await;
+                    long_press.set(None);
+                    open_at(root, point, MenuEntry::Pointer);
+                })));
+            },
+            onpointermove: move |_| cancel_long_press(),
+            onpointerup: move |_| cancel_long_press(),
+            onpointercancel: move |_| cancel_long_press(),
+            onkeydown: move |evt: Event<KeyboardData>| async move {
+                let pressed = match evt.key() {
+                    Key::ContextMenu => true,
+                    Key::F10 => evt.modifiers().shift(),
+                    _ => false,
+                };
+                if disabled || !pressed {
+                    return;
+                }
+                // Also keeps the browser from firing `contextmenu` at the focused element
+                evt.prevent_default();
+
+                let mut eval = document::eval(r#"
+                    const rect = document.activeElement.getBoundingClientRect();
+                    dioxus.send([rect.left, rect.bottom]);
+                "#);
+                let point = eval.recv::<(f64, f64)>().await.unwrap_or_default();
+                open_at(root, point, MenuEntry::First);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The component that pops out when the menu is open
+///
+/// The content is placed at the pointer and flips and shifts to stay inside of the collision boundary.
+/// It stays mounted until its exit animations have finished after it is closed.
+///
+/// Pressing `<Escape>` or interacting outside of the content closes the menu.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`
+///
+/// # CSS Variables
+///
+/// - `--meek-popper-available-width`: The width between the pointer and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the pointer and the edge of the boundary
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the pointer
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowDown>`: Moves focus to the next item.
+/// - `<ArrowUp>`: Moves focus to the previous item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last item.
+/// - `<Escape>`: Closes the menu.
+/// - Typing a character moves focus to the next item whose text starts with the typed characters.
+#[component]
+pub fn ContextMenuContent(
+    /// Offset in pixels from the pointer along the vertical axis. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the other side of the pointer and shifts to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+    /// Distance in pixels between the arrow and the edges of the content. Defaults to `0`
+    arrow_padding: Option<f64>,
+    /// Whether keyboard navigation wraps around from the last item to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+
+    /// Keep the content mounted even when the menu is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when focus moves back to the previously focused element after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+
+    let options = use_memo(use_reactive!(|align_offset, avoid_collisions, collision_padding, collision_boundary, arrow_padding| {
+        PopperOptions {
+            side: Side::Right,
+            align: Align::Start,
+            // Keep the content from opening right under the pointer
+            side_offset: 2.0,
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: arrow_padding.unwrap_or_default(),
+        }
+    }));
+    let anchor = use_memo(move || {
+        let root = root.read();
+        match root.point {
+            Some((x, y)) => PopperAnchor::Point(x, y),
+            None => PopperAnchor::Element(root.trigger_id()),
+        }
+    });
+
+    render_menu_content(
+        MenuContentOptions {
+            popper: options,
+            anchor,
+            // Right clicking the trigger again moves the menu, so it is dismissed like any other outside interaction
+            branches: Vec::new(),
+            looped: r#loop.unwrap_or(true),
+            force_mount: force_mount.unwrap_or_default(),
+            handlers: DismissableLayerHandlers {
+                onescapekeydown,
+                onpointerdownoutside,
+                onfocusoutside,
+                oninteractoutside,
+                ondismiss: None,
+            },
+            oncloseautofocus,
+        },
+        attrs,
+        children,
+    )
+}
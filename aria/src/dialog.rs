@@ -0,0 +1,357 @@
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    focus_scope::use_focus_scope,
+    presence::use_presence,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+};
+
+/// Handles the contextual state of a dialog
+///
+/// This context is shared by `Dialog` and `AlertDialog`
+pub(crate) struct DialogContext {
+    pub(crate) id: String,
+    pub(crate) title_id: Option<String>,
+    pub(crate) description_id: Option<String>,
+
+    pub(crate) modal: bool,
+
+    pub(crate) open: Signal<bool>,
+    pub(crate) onchange: Option<EventHandler<bool>>,
+}
+
+impl DialogContext {
+    pub(crate) fn new(id: Option<String>, modal: bool, open: Signal<bool>, onchange: Option<EventHandler<bool>>) -> Self {
+        Self {
+            id: id.unwrap_or(create_id()),
+            title_id: None,
+            description_id: None,
+
+            modal,
+
+            open,
+            onchange,
+        }
+    }
+
+    /// Get the formatted trigger id
+    pub(crate) fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    pub(crate) fn set_title(&mut self, id: Option<String>) {
+        self.title_id.replace(id.unwrap_or(format!("{}:title", self.id)));
+    }
+
+    pub(crate) fn set_description(&mut self, id: Option<String>) {
+        self.description_id.replace(id.unwrap_or(format!("{}:description", self.id)));
+    }
+
+    pub(crate) fn close(&mut self) {
+        *self.open.write() = false;
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(false);
+        }
+    }
+
+    pub(crate) fn open(&mut self) {
+        *self.open.write() = true;
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(true);
+        }
+    }
+
+    pub(crate) fn toggle(&mut self) {
+        if *self.open.peek() { self.close() }
+        else { self.open() }
+    }
+
+    /// Show the dialog, closing it first when it is already showing in the other mode
+    pub(crate) fn show(&self, modal: bool) {
+        let id = self.id.clone();
+        let method = if modal { "showModal" } else { "show" };
+        spawn(async move {
+            let eval = format!(r#"
+                const dialog = document.getElementById("{id}");
+                if (dialog?.open && dialog.matches(":modal") !== {modal}) dialog.close();
+                dialog?.{method}();
+            "#);
+            document::eval(eval.as_str()).await.unwrap();
+        });
+    }
+
+    pub(crate) fn hide(&self) {
+        let id = self.id.clone();
+        spawn(async move {
+            let eval = format!(r#"document.getElementById("{id}")?.close()"#);
+            document::eval(eval.as_str()).await.unwrap();
+        });
+    }
+}
+
+/// A window overlaid on either the primary window or another dialog window
+///
+/// When `modal` the content behind the dialog is inert and focus is kept inside of the dialog.
+/// Otherwise the user can still interact with the rest of the page and focus is not trapped.
+#[component]
+pub fn Dialog(
+    /// Passed state whether the dialog should be open or closed
+    open: Option<bool>,
+    /// Default open state of the dialog
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+    /// Whether the dialog is modal. Defaults to `true`
+    modal: Option<bool>,
+    /// User defined ID of the dialog
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_signal(|| default.unwrap_or_default());
+    let mut context = use_context_provider(|| Signal::new(DialogContext::new(id, modal.unwrap_or(true), state, onchange)));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(o) = open {
+            state.set(o);
+        }
+    }));
+
+    use_effect(use_reactive!(|modal| {
+        context.write().modal = modal.unwrap_or(true);
+    }));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// A button that toggles the dialog
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Open/Close the dialog.
+/// - `<Enter>`: Open/Close the dialog.
+#[component]
+pub fn DialogTrigger(
+    disabled: Option<bool>,
+
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut context = use_context::<Signal<DialogContext>>();
+    rsx! {
+        button {
+            r#type: "button",
+            id: context.read().trigger_id(),
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_haspopup: "dialog",
+            aria_expanded: *context.read().open.read(),
+            aria_controls: context.read().id.as_str(),
+
+            "data-state": if *context.read().open.read() { "open" } else { "closed" },
+
+            onclick: move |_| context.write().toggle(),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A `dialog` html element which contains the content to be rendered when it is open
+///
+/// The dialog stays open until its exit animations have finished after it is closed.
+///
+/// Pressing `<Escape>` or interacting outside of the content closes the dialog.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+#[component]
+pub fn DialogContent(
+    #[props(into)]
+    aria_labelledby: Option<String>,
+    #[props(into)]
+    aria_describedby: Option<String>,
+    /// Keep the content mounted even when the dialog is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when focus moves into the dialog after opening
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    onopenautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when focus moves back to the trigger after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the dialog open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the dialog
+    ///
+    /// Call `prevent_default` on the event to keep the dialog open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the dialog
+    ///
+    /// Call `prevent_default` on the event to keep the dialog open
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut context = use_context::<Signal<DialogContext>>();
+
+    let open = use_memo(move || *context.read().open.read());
+    let modal = use_memo(move || context.read().modal);
+    let looped = use_memo(|| true);
+    use_focus_scope(context.peek().id.clone(), open, modal, looped, onopenautofocus, oncloseautofocus);
+
+    // Keep the dialog showing until its exit animations have finished
+    let id = use_memo(move || context.read().id.clone());
+    let is_present = use_presence(id, open);
+
+    // Update showing and hiding the dialog based on the `open` and `modal` state
+    use_effect(move || {
+        if open() { context.peek().show(modal()) }
+        else if !is_present() { context.peek().hide() }
+    });
+
+    // Close the dialog when the user presses escape or interacts outside of it
+    let ondismiss = use_callback(move |_| context.write().close());
+    use_dismissable_layer(
+        context.peek().id.clone(),
+        open,
+        vec![context.peek().trigger_id()],
+        DismissableLayerHandlers {
+            onescapekeydown,
+            onpointerdownoutside,
+            oninteractoutside,
+            ondismiss: Some(ondismiss),
+            ..Default::default()
+        },
+    );
+
+    rsx! {
+        dialog {
+            id: context.read().id.as_str(),
+            role: "dialog",
+            aria_modal: context.read().modal,
+            aria_labelledby: aria_labelledby.as_deref().or(context.read().title_id.as_deref()),
+            aria_describedby: aria_describedby.as_deref().or(context.read().description_id.as_deref()),
+            "data-state": if *context.read().open.read() { "open" } else { "closed" },
+
+            ..attrs,
+
+            if is_present() || force_mount.unwrap_or_default() {
+                {children}
+            }
+        }
+    }
+}
+
+/// An accessible name to be announced when the dialog opens
+///
+/// Alternatively, `aria-label` or `aria-labelledby` can be provided to `DialogContent` and this component can be excluded.
+#[component]
+pub fn DialogTitle(
+    #[props(into)]
+    id: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut context = use_context::<Signal<DialogContext>>();
+
+    use_effect(move || {
+        context.write().set_title(id.clone());
+    });
+
+    rsx! {
+        h2 {
+            id: context.read().title_id.as_deref(),
+            ..attrs,
+            {children}
+        }
+    }
+}
+
+/// An accessible description to be announced when the dialog is opened
+///
+/// Alternatively, `aria-describedby` can be provided to `DialogContent` and this component can be exluded.
+#[component]
+pub fn DialogDescription(
+    #[props(into)]
+    id: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut context = use_context::<Signal<DialogContext>>();
+
+    use_effect(move || {
+        context.write().set_description(id.clone());
+    });
+
+    rsx! {
+        div {
+            id: context.read().description_id.as_deref(),
+            ..attrs,
+            {children}
+        }
+    }
+}
+
+/// A button that closes the dialog
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Close the dialog.
+/// - `<Enter>`: Close the dialog.
+#[component]
+pub fn DialogClose(
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    disabled: Option<bool>,
+
+    children: Element,
+) -> Element {
+    let mut context = use_context::<Signal<DialogContext>>();
+
+    rsx! {
+        button {
+            r#type: "button",
+            disabled: disabled,
+            aria_disabled: disabled,
+            onclick: move |_| context.write().close(),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::{cell::{Cell, RefCell}, rc::Rc};
+
+use dioxus::{document::Document, prelude::*};
+
+use crate::create_id;
+
+/// Ids of the currently active dismissable layers, ordered from the bottom to the top of the stack
+pub(crate) static LAYERS: GlobalSignal<Vec<String>> = Signal::global(Vec::new);
+
+/// What caused a dismissable layer to request being dismissed
+#[derive(Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum DismissReason {
+    /// The `<Escape>` key was pressed
+    EscapeKeyDown,
+    /// A pointer was pressed outside of the layer
+    PointerDownOutside,
+    /// Focus moved outside of the layer
+    FocusOutside,
+}
+
+/// Event passed to the handlers of a `DismissableLayer`
+///
+/// Call `prevent_default` to keep the layer from being dismissed.
+#[derive(Debug, Clone)]
+pub struct DismissableLayerEvent {
+    reason: DismissReason,
+    prevented: Rc<Cell<bool>>,
+}
+
+impl DismissableLayerEvent {
+    fn new(reason: DismissReason) -> Self {
+        Self {
+            reason,
+            prevented: Default::default(),
+        }
+    }
+
+    /// What caused the layer to request being dismissed
+    pub fn reason(&self) -> DismissReason {
+        self.reason
+    }
+
+    /// Prevent the layer from being dismissed
+    pub fn prevent_default(&self) {
+        self.prevented.set(true);
+    }
+
+    /// Whether `prevent_default` was called
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+}
+
+impl PartialEq for DismissableLayerEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.reason == other.reason && Rc::ptr_eq(&self.prevented, &other.prevented)
+    }
+}
+
+/// Event handlers for a dismissable layer
+#[derive(Default, Clone, PartialEq)]
+pub(crate) struct DismissableLayerHandlers {
+    pub onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    pub onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    pub onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    pub oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+    pub ondismiss: Option<EventHandler<()>>,
+}
+
+impl DismissableLayerHandlers {
+    /// Run the handlers for the reason and dismiss the layer if none of them prevented it
+    fn dispatch(&self, reason: DismissReason) {
+        let event = DismissableLayerEvent::new(reason);
+
+        let handler = match reason {
+            DismissReason::EscapeKeyDown => self.onescapekeydown.as_ref(),
+            DismissReason::PointerDownOutside => self.onpointerdownoutside.as_ref(),
+            DismissReason::FocusOutside => self.onfocusoutside.as_ref(),
+        };
+        if let Some(handler) = handler {
+            handler.call(event.clone());
+        }
+
+        if !reason.is_escape_key_down() {
+            if let Some(handler) = self.oninteractoutside.as_ref() {
+                handler.call(event.clone());
+            }
+        }
+
+        if !event.default_prevented() {
+            if let Some(ondismiss) = self.ondismiss.as_ref() {
+                ondismiss.call(());
+            }
+        }
+    }
+}
+
+/// Whether the layer is the top most active layer
+fn is_top_layer(id: &str) -> bool {
+    LAYERS.peek().last().is_some_and(|top| top == id)
+}
+
+/// Whether an interaction outside of the layer, that happened inside of the `containing` layers,
+/// is also outside of every layer stacked on top of it
+fn is_outside(id: &str, containing: &[String]) -> bool {
+    let layers = LAYERS.peek();
+    match layers.iter().position(|layer| layer == id) {
+        Some(index) => !layers[index + 1..].iter().any(|layer| containing.contains(layer)),
+        None => false,
+    }
+}
+
+fn deactivate(doc: &Rc<dyn Document>, id: &str, task: &mut Signal<Option<Task>>) {
+    if let Some(task) = task.write().take() {
+        task.cancel();
+    }
+    let _ = doc.eval(format!(r#"window.__meekLayers?.get("{id}")?.()"#));
+    LAYERS.write().retain(|layer| layer != id);
+}
+
+/// Make the element with the given `id` a dismissable layer while `active` is `true`
+///
+/// Elements with an id in `branches` are treated as part of the layer, e.g. the trigger that toggles it.
+pub(crate) fn use_dismissable_layer(
+    id: String,
+    active: Memo<bool>,
+    branches: Vec<String>,
+    handlers: DismissableLayerHandlers,
+) {
+    let doc = use_hook(dioxus::document::document);
+    let id = use_hook(|| id);
+    let mut task = use_signal(|| None::<Task>);
+
+    // Keep the latest handlers around without subscribing to them
+    let current = use_hook(|| Rc::new(RefCell::new(handlers.clone())));
+    *current.borrow_mut() = handlers;
+
+    use_effect({
+        let doc = doc.clone();
+        let id = id.clone();
+        let branches = format!("[{}]", branches.iter().map(|b| format!("{b:?}")).collect::<Vec<_>>().join(", "));
+        move || {
+            if !active() {
+                if task.peek().is_some() {
+                    deactivate(&doc, &id, &mut task);
+                }
+                return;
+            }
+
+            if task.peek().is_some() {
+                return;
+            }
+
+            LAYERS.write().push(id.clone());
+
+            let mut eval = doc.eval(format!(r#"
+                window.__meekLayers ??= new Map();
+
+                const within = (event) => {{
+                    // Pointer presses on the `::backdrop` of a modal `dialog` target the dialog itself
+                    const layer = document.getElementById("{id}");
+                    if (layer && event.target === layer && event.type === "pointerdown") {{
+                        const rect = layer.getBoundingClientRect();
+                        if (event.clientX < rect.left || event.clientX > rect.right
+                            || event.clientY < rect.top || event.clientY > rect.bottom) return false;
+                    }}
+                    return ["{id}", ...{branches}].some((id) => document.getElementById(id)?.contains(event.target));
+                }};
+                const containing = (target) => [...window.__meekLayers.keys()].filter((id) => document.getElementById(id)?.contains(target));
+
+                const isDialog = (id) => document.getElementById(id)?.tagName === "DIALOG";
+
+                const onkeydown = (event) => {{
+                    if (event.key !== "Escape") return;
+                    // Keep a native `dialog` from closing itself, the layer decides whether it is dismissed. Only the
+                    // top most dialog would close, layers stacked on top of it that aren't dialogs don't prevent that
+                    if (isDialog("{id}") && [...window.__meekLayers.keys()].filter(isDialog).at(-1) === "{id}") {{
+                        event.preventDefault();
+                    }}
+                    dioxus.send(["escape", []]);
+                }};
+                const onpointerdown = (event) => {{
+                    if (!within(event)) dioxus.send(["pointer", containing(event.target)]);
+                }};
+                const onfocusin = (event) => {{
+                    if (!within(event)) dioxus.send(["focus", containing(event.target)]);
+                }};
+
+                document.addEventListener("keydown", onkeydown, true);
+                // Wait a tick so the interaction that activated the layer does not dismiss it
+                const timer = setTimeout(() => {{
+                    document.addEventListener("pointerdown", onpointerdown, true);
+                    document.addEventListener("focusin", onfocusin);
+                }}, 0);
+
+                window.__meekLayers.set("{id}", () => {{
+                    clearTimeout(timer);
+                    document.removeEventListener("keydown", onkeydown, true);
+                    document.removeEventListener("pointerdown", onpointerdown, true);
+                    document.removeEventListener("focusin", onfocusin);
+                    window.__meekLayers.delete("{id}");
+                }});
+            "#));
+
+            let id = id.clone();
+            let current = current.clone();
+            task.set(Some(spawn(async move {
+                while let Ok((kind, containing)) = eval.recv::<(String, Vec<String>)>().await {
+                    let reason = match kind.as_str() {
+                        "escape" if is_top_layer(&id) => DismissReason::EscapeKeyDown,
+                        "pointer" if is_outside(&id, &containing) => DismissReason::PointerDownOutside,
+                        "focus" if is_outside(&id, &containing) => DismissReason::FocusOutside,
+                        _ => continue,
+                    };
+                    let handlers = current.borrow().clone();
+                    handlers.dispatch(reason);
+                }
+            })));
+        }
+    });
+
+    use_drop(move || {
+        if task.peek().is_some() {
+            deactivate(&doc, &id, &mut task);
+        }
+    });
+}
+
+/// A layer that can be dismissed by pressing `<Escape>` or interacting outside of it
+///
+/// Layers are kept in a global stack so that only the top most layer is dismissed when
+/// nested layers are active at the same time.
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Escape>`: Dismiss the top most layer.
+#[component]
+pub fn DismissableLayer(
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the layer from being dismissed
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the layer
+    ///
+    /// Call `prevent_default` on the event to keep the layer from being dismissed
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when focus moves outside of the layer
+    ///
+    /// Call `prevent_default` on the event to keep the layer from being dismissed
+    onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the layer
+    ///
+    /// Call `prevent_default` on the event to keep the layer from being dismissed
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when the layer should be dismissed
+    ondismiss: Option<EventHandler<()>>,
+
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes that are to be added to the layer `div` container
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let id = use_hook(|| id.unwrap_or(create_id()));
+    let active = use_memo(|| true);
+
+    use_dismissable_layer(id.clone(), active, Vec::new(), DismissableLayerHandlers {
+        onescapekeydown,
+        onpointerdownoutside,
+        onfocusoutside,
+        oninteractoutside,
+        ondismiss,
+    });
+
+    rsx! {
+        div {
+            id: id,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use dioxus::prelude::*;
+
+use crate::{
+    dismissable_layer::DismissableLayerHandlers,
+    menu::{render_menu_content, MenuContentOptions, MenuEntry, MenuRootState},
+    popper::{PopperAnchor, PopperOptions},
+    Align,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+    Side,
+};
+
+pub use crate::menu::{
+    MenuArrow as DropdownMenuArrow,
+    MenuArrowProps as DropdownMenuArrowProps,
+    MenuCheckboxItem as DropdownMenuCheckboxItem,
+    MenuCheckboxItemProps as DropdownMenuCheckboxItemProps,
+    MenuGroup as DropdownMenuGroup,
+    MenuGroupProps as DropdownMenuGroupProps,
+    MenuItem as DropdownMenuItem,
+    MenuItemIndicator as DropdownMenuItemIndicator,
+    MenuItemIndicatorProps as DropdownMenuItemIndicatorProps,
+    MenuItemProps as DropdownMenuItemProps,
+    MenuLabel as DropdownMenuLabel,
+    MenuLabelProps as DropdownMenuLabelProps,
+    MenuPortal as DropdownMenuPortal,
+    MenuPortalProps as DropdownMenuPortalProps,
+    MenuRadioGroup as DropdownMenuRadioGroup,
+    MenuRadioGroupProps as DropdownMenuRadioGroupProps,
+    MenuRadioItem as DropdownMenuRadioItem,
+    MenuRadioItemProps as DropdownMenuRadioItemProps,
+    MenuSelectEvent,
+    MenuSeparator as DropdownMenuSeparator,
+    MenuSeparatorProps as DropdownMenuSeparatorProps,
+    MenuSub as DropdownMenuSub,
+    MenuSubContent as DropdownMenuSubContent,
+    MenuSubContentProps as DropdownMenuSubContentProps,
+    MenuSubProps as DropdownMenuSubProps,
+    MenuSubTrigger as DropdownMenuSubTrigger,
+    MenuSubTriggerProps as DropdownMenuSubTriggerProps,
+};
+
+/// Dropdown Menu
+///
+/// Displays a menu to the user, such as a set of actions or functions, triggered by a button.
+#[component]
+pub fn DropdownMenu(
+    /// Passed state whether the menu should be open or closed
+    open: Option<bool>,
+    /// Default open state of the menu
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+    /// User defined ID of the menu content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let mut current = use_signal(|| open.or(default).unwrap_or_default());
+    use_context_provider(|| Signal::new(MenuRootState::new(id, current, onchange)));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            current.set(open);
+        }
+    }));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// The button that toggles the menu
+///
+/// By default the content is positioned against the trigger.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Opens the menu and focuses the first item.
+/// - `<Enter>`: Opens the menu and focuses the first item.
+/// - `<ArrowDown>`: Opens the menu and focuses the first item.
+/// - `<ArrowUp>`: Opens the menu and focuses the last item.
+#[component]
+pub fn DropdownMenuTrigger(
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut root = use_context::<Signal<MenuRootState>>();
+    let disabled = disabled.unwrap_or_default();
+    let open = *root.read().open.read();
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: root.read().trigger_id(),
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_haspopup: "menu",
+            aria_expanded: open,
+            aria_controls: if open { Some(root.read().id.clone()) } else { None },
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": disabled,
+
+            onpointerdown: move |evt: Event<PointerData>| {
+                // Only the primary button without `<Ctrl>` toggles, which opens the context menu on macOS
+                if disabled || evt.trigger_button() != Some(dioxus::html::input_data::MouseButton::Primary) || evt.modifiers().ctrl() {
+                    return;
+                }
+                let mut root = root.write();
+                let open = !*root.open.peek();
+                root.entry = MenuEntry::Pointer;
+                root.set_open(open);
+            },
+            onkeydown: move |evt: Event<KeyboardData>| {
+                if disabled {
+                    return;
+                }
+                let mut root = root.write();
+                let (open, entry) = match evt.key() {
+                    Key::Enter => (!*root.open.peek(), MenuEntry::First),
+                    Key::Character(c) if c == " " => (!*root.open.peek(), MenuEntry::First),
+                    Key::ArrowDown => (true, MenuEntry::First),
+                    Key::ArrowUp => (true, MenuEntry::Last),
+                    _ => return,
+                };
+                evt.prevent_default();
+                root.entry = entry;
+                root.set_open(open);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The component that pops out when the menu is open
+///
+/// The content is positioned against the trigger and flips and shifts to stay inside of the collision boundary.
+/// It stays mounted until its exit animations have finished after it is closed.
+///
+/// Pressing `<Escape>` or interacting outside of the content closes the menu.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`
+///
+/// # CSS Variables
+///
+/// - `--meek-popper-available-width`: The width between the trigger and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the trigger and the edge of the boundary
+/// - `--meek-popper-anchor-width`: The width of the trigger
+/// - `--meek-popper-anchor-height`: The height of the trigger
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the trigger and arrow
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowDown>`: Moves focus to the next item.
+/// - `<ArrowUp>`: Moves focus to the previous item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last item.
+/// - `<Escape>`: Closes the menu and moves focus back to the trigger.
+/// - Typing a character moves focus to the next item whose text starts with the typed characters.
+#[component]
+pub fn DropdownMenuContent(
+    /// The side of the trigger to place the content against. Defaults to `bottom`
+    #[props(into, default)]
+    side: Side,
+    /// Distance in pixels between the trigger and the content. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Alignment of the content along the side of the trigger. Defaults to `start`
+    #[props(into, default = Align::Start)]
+    align: Align,
+    /// Offset in pixels from the `align` position. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the opposite side and shifts along the trigger to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+    /// Distance in pixels between the arrow and the edges of the content. Defaults to `0`
+    arrow_padding: Option<f64>,
+    /// Whether keyboard navigation wraps around from the last item to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+
+    /// Keep the content mounted even when the menu is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when focus moves back to the trigger after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+
+    let options = use_memo(use_reactive!(|side, side_offset, align, align_offset, avoid_collisions, collision_padding, collision_boundary, arrow_padding| {
+        PopperOptions {
+            side,
+            align,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: arrow_padding.unwrap_or_default(),
+        }
+    }));
+    let anchor = use_memo(move || PopperAnchor::Element(root.read().trigger_id()));
+    let trigger = root.peek().trigger_id();
+
+    render_menu_content(
+        MenuContentOptions {
+            popper: options,
+            anchor,
+            branches: vec![trigger],
+            looped: r#loop.unwrap_or(true),
+            force_mount: force_mount.unwrap_or_default(),
+            handlers: DismissableLayerHandlers {
+                onescapekeydown,
+                onpointerdownoutside,
+                onfocusoutside,
+                oninteractoutside,
+                ondismiss: None,
+            },
+            oncloseautofocus,
+        },
+        attrs,
+        children,
+    )
+}
@@ -0,0 +1,234 @@
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::{document::Document, prelude::*};
+
+use crate::create_id;
+
+/// Selector matching every element that can be reached with `<Tab>`
+pub(crate) const TABBABLE: &str = r#"a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [tabindex]:not([tabindex="-1"]), [contenteditable="true"]"#;
+
+/// Event passed to the auto focus handlers of a `FocusScope`
+///
+/// Call `prevent_default` to stop the scope from moving focus itself. This allows
+/// the handler to focus a different element instead.
+#[derive(Debug, Clone, Default)]
+pub struct FocusScopeEvent {
+    prevented: Rc<Cell<bool>>,
+}
+
+impl FocusScopeEvent {
+    /// Prevent the scope from moving focus
+    pub fn prevent_default(&self) {
+        self.prevented.set(true);
+    }
+
+    /// Whether `prevent_default` was called
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+}
+
+impl PartialEq for FocusScopeEvent {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.prevented, &other.prevented)
+    }
+}
+
+/// Manage focus for the element with the given `id` while `active` is `true`
+///
+/// When the scope becomes active the previously focused element is remembered and focus is moved
+/// into the scope; to the first `[autofocus]` element, the first tabbable element, or the scope itself.
+/// When the scope becomes inactive, or is dropped, focus is restored to the remembered element.
+pub(crate) fn use_focus_scope(
+    id: String,
+    active: Memo<bool>,
+    trapped: Memo<bool>,
+    looped: Memo<bool>,
+    onmountautofocus: Option<EventHandler<FocusScopeEvent>>,
+    onunmountautofocus: Option<EventHandler<FocusScopeEvent>>,
+) {
+    let doc = use_hook(dioxus::document::document);
+    let id = use_hook(|| id);
+    let mut mounted = use_signal(|| false);
+
+    use_effect({
+        let doc = doc.clone();
+        let id = id.clone();
+        move || {
+            let trapped = trapped();
+            let looped = looped();
+
+            if !active() {
+                if *mounted.peek() {
+                    mounted.set(false);
+                    unmount_focus_scope(&doc, &id, onunmountautofocus);
+                }
+                return;
+            }
+
+            if !*mounted.peek() {
+                mounted.set(true);
+                let _ = doc.eval(format!(r#"
+                    window.__meekFocusScopes ??= {{}};
+                    const previous = document.activeElement;
+                    window.__meekFocusScopes["{id}"] = {{
+                        previous,
+                        cleanup() {{ }},
+                        restore() {{
+                            delete window.__meekFocusScopes["{id}"];
+                            // Wait for the scope to be removed before moving focus back
+                            setTimeout(() => {{
+                                const target = previous && document.contains(previous) ? previous : document.body;
+                                target.focus({{ preventScroll: true }});
+                            }}, 0);
+                        }},
+                    }};
+                "#));
+
+                let event = FocusScopeEvent::default();
+                if let Some(handler) = onmountautofocus.as_ref() {
+                    handler.call(event.clone());
+                }
+                if !event.default_prevented() {
+                    let _ = doc.eval(format!(r#"
+                        requestAnimationFrame(() => {{
+                            const scope = document.getElementById("{id}");
+                            if (!scope || scope.contains(document.activeElement)) return;
+                            const candidate = scope.querySelector("[autofocus]")
+                                ?? [...scope.querySelectorAll(`{TABBABLE}`)].find((el) => el.offsetParent !== null);
+                            (candidate ?? scope).focus({{ preventScroll: true }});
+                        }});
+                    "#));
+                }
+            }
+
+            // (Re)bind the trapping and looping listeners
+            let _ = doc.eval(format!(r#"
+                const entry = window.__meekFocusScopes?.["{id}"];
+                const scope = document.getElementById("{id}");
+                if (entry && scope) {{
+                    entry.cleanup();
+
+                    let last = null;
+                    const onfocusin = (event) => {{
+                        if (scope.contains(event.target)) last = event.target;
+                        else (last ?? scope).focus({{ preventScroll: true }});
+                    }};
+                    const onfocusout = (event) => {{
+                        // `relatedTarget` is `null` when focus moves to the body, i.e. the focused element was removed
+                        if (event.relatedTarget !== null && !scope.contains(event.relatedTarget)) {{
+                            (last ?? scope).focus({{ preventScroll: true }});
+                        }}
+                    }};
+                    const onkeydown = (event) => {{
+                        if (event.key !== "Tab" || event.altKey || event.ctrlKey || event.metaKey) return;
+
+                        const tabbables = [...scope.querySelectorAll(`{TABBABLE}`)].filter((el) => el.offsetParent !== null);
+                        const first = tabbables[0];
+                        const last = tabbables[tabbables.length - 1];
+
+                        if (!first) {{
+                            if (document.activeElement === scope) event.preventDefault();
+                        }} else if (!event.shiftKey && document.activeElement === last) {{
+                            event.preventDefault();
+                            if ({looped}) first.focus({{ preventScroll: true }});
+                        }} else if (event.shiftKey && document.activeElement === first) {{
+                            event.preventDefault();
+                            if ({looped}) last.focus({{ preventScroll: true }});
+                        }}
+                    }};
+
+                    if ({trapped}) {{
+                        document.addEventListener("focusin", onfocusin);
+                        scope.addEventListener("focusout", onfocusout);
+                    }}
+                    if ({trapped} || {looped}) scope.addEventListener("keydown", onkeydown);
+
+                    entry.cleanup = () => {{
+                        document.removeEventListener("focusin", onfocusin);
+                        scope.removeEventListener("focusout", onfocusout);
+                        scope.removeEventListener("keydown", onkeydown);
+                    }};
+                }}
+            "#));
+        }
+    });
+
+    use_drop(move || {
+        if *mounted.peek() {
+            unmount_focus_scope(&doc, &id, onunmountautofocus);
+        }
+    });
+}
+
+/// Release the listeners of a focus scope and restore focus unless the handler prevents it
+fn unmount_focus_scope(doc: &Rc<dyn Document>, id: &str, onunmountautofocus: Option<EventHandler<FocusScopeEvent>>) {
+    let _ = doc.eval(format!(r#"window.__meekFocusScopes?.["{id}"]?.cleanup()"#));
+
+    let event = FocusScopeEvent::default();
+    if let Some(handler) = onunmountautofocus.as_ref() {
+        handler.call(event.clone());
+    }
+
+    if event.default_prevented() {
+        let _ = doc.eval(format!(r#"delete window.__meekFocusScopes?.["{id}"]"#));
+    } else {
+        let _ = doc.eval(format!(r#"window.__meekFocusScopes?.["{id}"]?.restore()"#));
+    }
+}
+
+/// Manages focus for the elements inside of it
+///
+/// When mounted, focus is moved to the first element with an `autofocus` attribute, the first tabbable
+/// element, or the scope itself. When unmounted, focus is returned to the element that was focused before the scope
+/// was mounted.
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: [Loop] Moves focus from the last tabbable element to the first.
+/// - `<Shift+Tab>`: [Loop] Moves focus from the first tabbable element to the last.
+#[component]
+pub fn FocusScope(
+    /// Whether focus is kept inside of the scope. Defaults to `false`
+    trapped: Option<bool>,
+    /// Whether `<Tab>` and `<Shift+Tab>` wrap around at the edges of the scope. Defaults to `false`
+    r#loop: Option<bool>,
+    /// Handler that is run when focus is moved into the scope on mount
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    onmountautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when focus is restored on unmount
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    onunmountautofocus: Option<EventHandler<FocusScopeEvent>>,
+
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes that are to be added to the scope `div` container
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let id = use_hook(|| id.unwrap_or(create_id()));
+
+    let active = use_memo(|| true);
+    let trapped = use_memo(use_reactive!(|trapped| trapped.unwrap_or_default()));
+    let looped = use_memo(use_reactive!(|r#loop| r#loop.unwrap_or_default()));
+
+    use_focus_scope(id.clone(), active, trapped, looped, onmountautofocus, onunmountautofocus);
+
+    rsx! {
+        div {
+            id: id,
+            tabindex: -1,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
@@ -0,0 +1,490 @@
+use std::{collections::HashMap, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::{cell_navigation::{find_cell, CellLayout, CellTarget}, create_id, Optional, OptionalMaybeSignal};
+
+/// Whether rows of a grid can be selected
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum GridSelection {
+    #[default]
+    None,
+    Single,
+    Multiple,
+}
+
+impl<A: AsRef<str>> From<A> for GridSelection {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "none" => Self::None,
+            "single" => Self::Single,
+            "multiple" => Self::Multiple,
+            other => panic!("unknown grid selection: '{other}'")
+        }
+    }
+}
+
+/// How the rows are sorted by a column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridSort {
+    /// The column can be sorted, but isn't
+    None,
+    Ascending,
+    Descending,
+    /// The rows are sorted by the column with an algorithm other than ascending or descending
+    Other,
+}
+
+impl GridSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Rows and cells of a grid, only the cells receive focus
+const LAYOUT: CellLayout = CellLayout {
+    rows: "[data-meek-grid-row]",
+    cells: "[data-meek-grid-cell]",
+    row_focus: false,
+};
+
+/// Handles the contextual state of a grid
+#[derive(Debug)]
+struct GridState {
+    id: String,
+    pub selection: GridSelection,
+    pub page_size: usize,
+
+    /// Id of the cell that currently has focus
+    focused: Option<String>,
+    /// Ids of the rendered cells in the order they were rendered
+    cells: Vec<String>,
+    /// Mounted data of the rendered cells by the id of their element
+    mounted: HashMap<String, Rc<MountedData>>,
+    /// Values of the rendered rows that can be selected, in the order they were rendered
+    rows: Vec<String>,
+    /// Value of the row each rendered cell belongs to, by the id of the cell
+    cell_rows: HashMap<String, String>,
+
+    selected: Signal<Vec<String>>,
+    onchange: Option<EventHandler<Vec<String>>>,
+}
+
+impl GridState {
+    /// Limit the selected rows based on the selection mode
+    fn limit(selection: GridSelection, values: Vec<String>) -> Vec<String> {
+        match selection {
+            GridSelection::None => Vec::new(),
+            GridSelection::Single => values.into_iter().take(1).collect(),
+            GridSelection::Multiple => values,
+        }
+    }
+
+    /// Replace the selected rows without notifying `onchange`
+    fn sync(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.selection, values);
+        if *self.selected.peek() != values {
+            self.selected.set(values);
+        }
+    }
+
+    /// Check if a row is selected
+    fn is_selected(&self, value: impl AsRef<str>) -> bool {
+        self.selected.read().iter().any(|selected| selected == value.as_ref())
+    }
+
+    /// Replace the selection
+    fn set(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.selection, values);
+        if *self.selected.peek() != values {
+            self.selected.set(values);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(self.selected.peek().clone());
+            }
+        }
+    }
+
+    /// Select a single row, or toggle it when multiple rows can be selected
+    fn select(&mut self, value: &str) {
+        let values = if self.selection.is_single() {
+            vec![value.to_string()]
+        } else if self.is_selected(value) {
+            self.selected.peek().iter().filter(|selected| *selected != value).cloned().collect()
+        } else {
+            let mut values = self.selected.peek().clone();
+            values.push(value.to_string());
+            values
+        };
+        self.set(values);
+    }
+
+    /// Select every rendered row, or unselect them when they are all selected already
+    fn toggle_all(&mut self) {
+        if self.rows.iter().all(|value| self.is_selected(value)) {
+            self.set(Vec::new());
+        } else {
+            self.set(self.rows.clone());
+        }
+    }
+
+    /// Whether the cell is the one that is reached when tabbing into the grid
+    fn is_tab_stop(&self, id: &str) -> bool {
+        match self.focused.as_deref().filter(|focused| self.mounted.contains_key(*focused)) {
+            Some(focused) => focused == id,
+            None => self.cells.first().is_some_and(|first| first == id),
+        }
+    }
+}
+
+/// Grid
+///
+/// A table whose cells are navigated with the arrow keys, with optional row selection.
+///
+/// When only part of the rows are rendered, set `row_count` to the total number of rows and `index` on each
+/// `GridRow` so assistive technology knows where the rendered rows are.
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the cell that had focus last, or the first cell.
+/// - `<ArrowRight>`: Moves focus to the next cell in the row.
+/// - `<ArrowLeft>`: Moves focus to the previous cell in the row.
+/// - `<ArrowDown>`: Moves focus to the cell in the same column of the next row.
+/// - `<ArrowUp>`: Moves focus to the cell in the same column of the previous row.
+/// - `<PageDown>`: Moves focus down by `page_size` rows, staying in the same column.
+/// - `<PageUp>`: Moves focus up by `page_size` rows, staying in the same column.
+/// - `<Home>`: Moves focus to the first cell in the row.
+/// - `<End>`: Moves focus to the last cell in the row.
+/// - `<Ctrl+Home>`: Moves focus to the first cell in the grid.
+/// - `<Ctrl+End>`: Moves focus to the last cell in the grid.
+///
+/// When rows can be selected:
+///
+/// - `<Shift+Space>`: Selects the row of the focused cell, or toggles it when multiple rows can be selected.
+/// - `<Ctrl+A>`: Selects every rendered row, or unselects them when they are all selected.
+#[component]
+pub fn Grid(
+    /// Selected row(s)
+    ///
+    /// When a signal is passed it is kept in sync with the selected rows
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default row(s) to select
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the selected rows change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether no, one or many rows can be selected. Defaults to `none`
+    #[props(into, default)]
+    selection: GridSelection,
+    /// Total number of rows, including header rows, when only part of them are rendered
+    row_count: Option<usize>,
+    /// Total number of columns, when only part of them are rendered
+    col_count: Option<usize>,
+    /// Number of rows `<PageUp>` and `<PageDown>` move focus by. Defaults to `10`
+    page_size: Option<usize>,
+    /// User defined ID of the grid
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the grid `table` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let selected = value.clone().as_signal(GridState::limit(selection, default.unwrap_or_default()));
+    let mut state = use_context_provider(|| Signal::new(GridState {
+        id: id.unwrap_or(create_id()),
+        selection,
+        page_size: page_size.unwrap_or(10),
+        focused: None,
+        cells: Vec::new(),
+        mounted: HashMap::new(),
+        rows: Vec::new(),
+        cell_rows: HashMap::new(),
+        selected,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|selection, page_size| {
+        let mut state = state.write();
+        state.selection = selection;
+        state.page_size = page_size.unwrap_or(10);
+    }));
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        let (id, focused, page_size, selection) = {
+            let state = state.read();
+            let Some(focused) = state.focused.clone() else {
+                return;
+            };
+            (state.id.clone(), focused, state.page_size, state.selection)
+        };
+
+        let modifiers = evt.modifiers();
+        let ctrl = modifiers.ctrl() || modifiers.meta();
+        let target = match evt.key() {
+            Key::ArrowRight => CellTarget::NextCell,
+            Key::ArrowLeft => CellTarget::PreviousCell,
+            Key::ArrowDown => CellTarget::NextRow,
+            Key::ArrowUp => CellTarget::PreviousRow,
+            Key::PageDown => CellTarget::NextPage(page_size),
+            Key::PageUp => CellTarget::PreviousPage(page_size),
+            Key::Home if ctrl => CellTarget::FirstInGrid,
+            Key::End if ctrl => CellTarget::LastInGrid,
+            Key::Home => CellTarget::FirstCell,
+            Key::End => CellTarget::LastCell,
+            Key::Character(ref character) if character == " " && modifiers.shift() && !selection.is_none() => {
+                evt.prevent_default();
+                let mut state = state.write();
+                if let Some(row) = state.cell_rows.get(&focused).cloned() {
+                    state.select(&row);
+                }
+                return;
+            }
+            Key::Character(ref character) if character.eq_ignore_ascii_case("a") && ctrl && selection.is_multiple() => {
+                evt.prevent_default();
+                state.write().toggle_all();
+                return;
+            }
+            _ => return,
+        };
+        evt.prevent_default();
+
+        let Some(target) = find_cell(&id, &focused, LAYOUT, target).await else {
+            return;
+        };
+        let data = state.read().mounted.get(&target).cloned();
+        if let Some(data) = data {
+            let _ = data.set_focus(true).await;
+        }
+    };
+
+    let grid = state.read();
+
+    rsx! {
+        table {
+            role: "grid",
+            id: grid.id.as_str(),
+            aria_multiselectable: if grid.selection.is_none() { None } else { Some(grid.selection.is_multiple()) },
+            aria_rowcount: row_count,
+            aria_colcount: col_count,
+
+            onkeydown: onkeydown,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Shares the value of a row with its cells
+#[derive(Debug, Clone, PartialEq)]
+struct GridRowState {
+    value: Option<String>,
+}
+
+/// A row in the grid
+///
+/// Header rows are also `GridRow`s, so their cells can be navigated to.
+///
+/// # Data Attributes
+///
+/// - `[data-selected]`: Present when selected
+#[component]
+pub fn GridRow(
+    /// The unique value of the row, required for the row to be selected
+    ///
+    /// This is what is returned when the `onchange` event is run
+    #[props(into)]
+    value: Option<String>,
+    /// Position of the row among all the rows of the grid, starting at `1`. Set it when only part of the rows are
+    /// rendered
+    index: Option<usize>,
+
+    /// Remaining attributes to add to the row `tr` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<GridState>>();
+    let mut row = use_context_provider(|| Signal::new(GridRowState { value: value.clone() }));
+
+    use_effect(use_reactive!(|value| {
+        let mut state = state.write();
+        let previous = row.peek().value.clone();
+        if let Some(previous) = previous {
+            state.rows.retain(|row| *row != previous);
+        }
+        if let Some(value) = value.clone() {
+            state.rows.push(value);
+        }
+        row.write().value = value;
+    }));
+
+    use_drop(move || {
+        if let (Ok(mut state), Ok(row)) = (state.try_write(), row.try_peek()) {
+            if let Some(value) = row.value.as_ref() {
+                state.rows.retain(|row| row != value);
+            }
+        }
+    });
+
+    let grid = state.read();
+    let selectable = !grid.selection.is_none() && value.is_some();
+    let selected = value.as_ref().is_some_and(|value| grid.is_selected(value));
+
+    rsx! {
+        tr {
+            role: "row",
+            aria_rowindex: index,
+            aria_selected: if selectable { Some(selected) } else { None },
+            "data-meek-grid-row": true,
+            "data-selected": selected,
+
+            onclick: move |_| {
+                let value = row.peek().value.clone();
+                let mut state = state.write();
+                if let (false, Some(value)) = (state.selection.is_none(), value) {
+                    state.select(&value);
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Register a cell with the grid and render it as a `th` tag when it is a header, or a `td` tag otherwise
+fn render_cell(header: bool, role: &'static str, attrs: Vec<Attribute>, children: Element) -> Element {
+    let mut state = use_context::<Signal<GridState>>();
+    let row = use_context::<Signal<GridRowState>>();
+    let id = use_hook(create_id);
+
+    use_effect({
+        let id = id.clone();
+        move || state.write().cells.push(id.clone())
+    });
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            let value = row.read().value.clone();
+            let mut state = state.write();
+            match value {
+                Some(value) => state.cell_rows.insert(id.clone(), value),
+                None => state.cell_rows.remove(&id),
+            };
+        }
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.cells.retain(|cell| *cell != id);
+                state.mounted.remove(&id);
+                state.cell_rows.remove(&id);
+            }
+        }
+    });
+
+    let tabindex = if state.read().is_tab_stop(&id) { 0 } else { -1 };
+    let onfocus = {
+        let id = id.clone();
+        move |_| state.write().focused = Some(id.clone())
+    };
+    let onmounted = {
+        let id = id.clone();
+        move |v: Event<MountedData>| {
+            state.write().mounted.insert(id.clone(), v.data());
+        }
+    };
+
+    if header {
+        rsx! {
+            th {
+                role: role,
+                id: id.as_str(),
+                tabindex: tabindex,
+                "data-meek-grid-cell": true,
+                onfocus: onfocus,
+                onmounted: onmounted,
+                ..attrs,
+                {children}
+            }
+        }
+    } else {
+        rsx! {
+            td {
+                role: role,
+                id: id.as_str(),
+                tabindex: tabindex,
+                "data-meek-grid-cell": true,
+                onfocus: onfocus,
+                onmounted: onmounted,
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+/// A cell in a row of the grid
+#[component]
+pub fn GridCell(
+    /// Remaining attributes to add to the cell `td` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_cell(false, "gridcell", attrs, children)
+}
+
+/// A header cell that labels the rows of its column
+#[component]
+pub fn GridColumnHeader(
+    /// How the rows are sorted by this column. Leave it unset when the column can't be sorted
+    sort: Option<GridSort>,
+
+    /// Remaining attributes to add to the header `th` tag
+    #[props(extends = GlobalAttributes)]
+    mut attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    if let Some(sort) = sort {
+        attrs.push(Attribute::new("aria-sort", sort.as_str(), None, false));
+    }
+    render_cell(true, "columnheader", attrs, children)
+}
+
+/// A header cell that labels the cells of its row
+#[component]
+pub fn GridRowHeader(
+    /// Remaining attributes to add to the header `th` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_cell(true, "rowheader", attrs, children)
+}
@@ -0,0 +1,309 @@
+use std::{collections::{HashMap, HashSet}, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::dom_order;
+
+/// Keeps track of the rendered items of a hierarchical widget, like `Tree` and `Treegrid`, and which are expanded
+///
+/// Items register themselves with `use_hierarchy_item`.
+#[derive(Debug)]
+pub(crate) struct Hierarchy {
+    /// Id of the element that contains the items
+    container: String,
+    /// Selector matching the elements of the items
+    selector: &'static str,
+
+    /// Values of the rendered items by the id of their element
+    pub ids: HashMap<String, String>,
+    /// Mounted data of the rendered items, and their parts that receive focus, by the id of their element
+    pub mounted: HashMap<String, Rc<MountedData>>,
+    /// Values of the rendered items by the value of their parent, in the order they are rendered
+    children: HashMap<Option<String>, Vec<String>>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+    /// Items that can be expanded, even when their children haven't been rendered
+    expandable: HashSet<String>,
+
+    expanded: Signal<HashSet<String>>,
+    onexpandedchange: Option<EventHandler<HashSet<String>>>,
+}
+
+impl Hierarchy {
+    pub fn new(
+        container: String,
+        selector: &'static str,
+        expanded: Signal<HashSet<String>>,
+        onexpandedchange: Option<EventHandler<HashSet<String>>>,
+    ) -> Self {
+        Self {
+            container,
+            selector,
+            ids: HashMap::new(),
+            mounted: HashMap::new(),
+            children: HashMap::new(),
+            ordering: false,
+            expandable: HashSet::new(),
+            expanded,
+            onexpandedchange,
+        }
+    }
+
+    /// Replace the expanded items without notifying `onexpandedchange`
+    pub fn sync_expanded(&mut self, values: HashSet<String>) {
+        if *self.expanded.peek() != values {
+            self.expanded.set(values);
+        }
+    }
+
+    /// Check if an item is expanded
+    pub fn is_expanded(&self, value: impl AsRef<str>) -> bool {
+        self.expanded.read().contains(value.as_ref())
+    }
+
+    /// Check if an item can be expanded
+    pub fn is_expandable(&self, value: impl AsRef<str>) -> bool {
+        self.expandable.contains(value.as_ref())
+    }
+
+    /// Expand or collapse the given items
+    pub fn set_expanded<'a>(&mut self, values: impl IntoIterator<Item = &'a String>, expanded: bool) {
+        let values: Vec<&String> = values.into_iter()
+            .filter(|value| self.expandable.contains(*value) && self.expanded.peek().contains(*value) != expanded)
+            .collect();
+        if values.is_empty() {
+            return;
+        }
+
+        let mut current = self.expanded.write();
+        for value in values {
+            if expanded {
+                current.insert(value.clone());
+            } else {
+                current.remove(value);
+            }
+        }
+        drop(current);
+
+        if let Some(onexpandedchange) = self.onexpandedchange.as_ref() {
+            onexpandedchange.call(self.expanded.peek().clone());
+        }
+    }
+
+    /// Toggle whether an item is expanded
+    pub fn toggle(&mut self, value: &String) {
+        let expanded = self.is_expanded(value);
+        self.set_expanded([value], !expanded);
+    }
+
+    /// Values of the items at the same level as the given item, including the item itself
+    pub fn siblings(&self, value: &str) -> Vec<String> {
+        self.children.values()
+            .find(|children| children.iter().any(|child| child == value))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Collect the rendered descendants of an item in the order they are rendered
+    pub fn rendered(&self, parent: Option<String>, values: &mut Vec<String>) {
+        for child in self.children.get(&parent).into_iter().flatten() {
+            values.push(child.clone());
+            self.rendered(Some(child.clone()), values);
+        }
+    }
+
+    /// Value of the first item at the top level
+    pub fn first(&self) -> Option<&String> {
+        self.children.get(&None).and_then(|roots| roots.first())
+    }
+
+    /// Position of the item among its siblings and the number of siblings, as `(posinset, setsize)`
+    pub fn position(&self, parent: Option<String>, value: &str) -> (usize, usize) {
+        let siblings = self.children.get(&parent).map(Vec::as_slice).unwrap_or_default();
+        let index = siblings.iter().position(|sibling| sibling == value).unwrap_or_default();
+        (index + 1, siblings.len())
+    }
+
+    /// Move an item over to its new value, keeping its position among its siblings and its children
+    fn rename(&mut self, from: &str, to: &str) {
+        for sibling in self.children.values_mut().flatten().filter(|sibling| *sibling == from) {
+            *sibling = to.to_string();
+        }
+        if let Some(children) = self.children.remove(&Some(from.to_string())) {
+            self.children.insert(Some(to.to_string()), children);
+        }
+        if self.expandable.remove(from) {
+            self.expandable.insert(to.to_string());
+        }
+    }
+
+    /// The rendered items by the value of their parent, sorted by the position of their elements in `order`
+    fn ordered_children(&self, order: &[String]) -> HashMap<Option<String>, Vec<String>> {
+        let position: HashMap<&str, usize> = order.iter()
+            .enumerate()
+            .filter_map(|(index, id)| Some((self.ids.get(id)?.as_str(), index)))
+            .collect();
+        let mut children = self.children.clone();
+        for siblings in children.values_mut() {
+            siblings.sort_by_key(|sibling| position.get(sibling.as_str()).copied().unwrap_or(usize::MAX));
+        }
+        children
+    }
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut hierarchy: Signal<Hierarchy>) {
+    if std::mem::replace(&mut hierarchy.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let (container, selector) = {
+            let mut hierarchy = hierarchy.write();
+            hierarchy.ordering = false;
+            (hierarchy.container.clone(), hierarchy.selector)
+        };
+        let order = dom_order(&container, selector).await;
+        let children = hierarchy.peek().ordered_children(&order);
+        if children != hierarchy.peek().children {
+            hierarchy.write().children = children;
+        }
+    });
+}
+
+/// Register an item with the hierarchy and return whether it is expanded
+///
+/// `id` is the id of the item's element and `parent` the value of the item it is nested in. `onexpand` is run
+/// with the value of the item whenever it is expanded.
+pub(crate) fn use_hierarchy_item(
+    mut hierarchy: Signal<Hierarchy>,
+    id: String,
+    parent: Option<Memo<String>>,
+    value: Memo<String>,
+    expandable: bool,
+    onexpand: Option<EventHandler<String>>,
+) -> Memo<bool> {
+    let expanded = use_memo(move || hierarchy.read().is_expanded(value.read().as_str()));
+    let mut registered = use_signal(|| None::<String>);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            let value = value();
+            let previous = registered.peek().clone();
+            {
+                let mut hierarchy = hierarchy.write();
+                match previous.as_deref() {
+                    Some(previous) => hierarchy.rename(previous, &value),
+                    None => {
+                        let parent = parent.map(|parent| parent.peek().clone());
+                        hierarchy.children.entry(parent).or_default().push(value.clone());
+                    }
+                }
+                hierarchy.ids.insert(id.clone(), value.clone());
+            }
+            registered.set(Some(value));
+            if previous.is_none() {
+                schedule_order(hierarchy);
+            }
+        }
+    });
+
+    use_effect(use_reactive!(|expandable| {
+        let mut hierarchy = hierarchy.write();
+        if expandable {
+            hierarchy.expandable.insert(value.peek().clone());
+        } else {
+            hierarchy.expandable.remove(value.peek().as_str());
+        }
+    }));
+
+    use_effect(move || {
+        if expanded() {
+            if let Some(onexpand) = onexpand {
+                onexpand.call(value.peek().clone());
+            }
+        }
+    });
+
+    use_drop(move || {
+        if let Ok(mut hierarchy) = hierarchy.try_write() {
+            let value = value.peek();
+            let parent = parent.and_then(|parent| parent.try_peek().ok().map(|parent| parent.clone()));
+            if let Some(siblings) = hierarchy.children.get_mut(&parent) {
+                siblings.retain(|sibling| *sibling != *value);
+            }
+            hierarchy.expandable.remove(value.as_str());
+            hierarchy.ids.remove(&id);
+            hierarchy.mounted.remove(&id);
+        }
+    });
+
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `test` with a hierarchy where `a` contains `a1` and `a2`, and `b` is at the top level next to `a`
+    ///
+    /// The items were registered in the order `b`, `a2`, `a`, `a1`, their elements have the ids `#b`, `#a2`...
+    fn with_hierarchy(test: impl FnOnce(&mut Hierarchy)) {
+        let dom = VirtualDom::prebuilt(VNode::empty);
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| {
+            let mut hierarchy = Hierarchy::new("tree".into(), "[data-item]", Signal::new(HashSet::new()), None);
+            for (parent, value) in [(None, "b"), (Some("a"), "a2"), (None, "a"), (Some("a"), "a1")] {
+                hierarchy.children.entry(parent.map(String::from)).or_default().push(value.to_string());
+                hierarchy.ids.insert(format!("#{value}"), value.to_string());
+            }
+            test(&mut hierarchy);
+        }));
+    }
+
+    fn values(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn position_counts_siblings() {
+        with_hierarchy(|hierarchy| {
+            assert_eq!(hierarchy.position(None, "b"), (1, 2));
+            assert_eq!(hierarchy.position(Some("a".into()), "a1"), (2, 2));
+            assert_eq!(hierarchy.position(Some("x".into()), "x1"), (1, 0));
+        });
+    }
+
+    #[test]
+    fn ordered_children_follow_document_order() {
+        with_hierarchy(|hierarchy| {
+            let children = hierarchy.ordered_children(&values(&["#a", "#a1", "#a2", "#b"]));
+            assert_eq!(children[&None], values(&["a", "b"]));
+            assert_eq!(children[&Some("a".into())], values(&["a1", "a2"]));
+        });
+    }
+
+    #[test]
+    fn ordered_children_keep_unknown_items_last() {
+        with_hierarchy(|hierarchy| {
+            let children = hierarchy.ordered_children(&values(&["#other", "#a2", "#b"]));
+            assert_eq!(children[&None], values(&["b", "a"]));
+            assert_eq!(children[&Some("a".into())], values(&["a2", "a1"]));
+        });
+    }
+
+    #[test]
+    fn rename_keeps_position_and_children() {
+        with_hierarchy(|hierarchy| {
+            hierarchy.expandable.insert("a".into());
+            hierarchy.rename("a", "c");
+            assert_eq!(hierarchy.children[&None], values(&["b", "c"]));
+            assert_eq!(hierarchy.children[&Some("c".into())], values(&["a2", "a1"]));
+            assert!(!hierarchy.children.contains_key(&Some("a".into())));
+            assert!(hierarchy.is_expandable("c"));
+            assert!(!hierarchy.is_expandable("a"));
+        });
+    }
+}
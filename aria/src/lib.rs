@@ -1,8 +1,37 @@
 mod accordian;
 mod alert_dialog;
+mod dialog;
+mod dismissable_layer;
+mod focus_scope;
 mod portal;
+mod presence;
+mod roving_focus;
 mod button;
 mod checkbox;
+mod tabs;
+mod radio_group;
+mod switch;
+mod slider;
+mod popper;
+mod popover;
+mod tooltip;
+mod typeahead;
+mod menu;
+mod dropdown_menu;
+mod context_menu;
+mod select;
+mod combobox;
+mod listbox;
+mod hierarchy;
+mod cell_navigation;
+mod tree;
+mod treegrid;
+mod grid;
+mod toggle_group;
+mod toolbar;
+mod breadcrumb;
+mod carousel;
+mod collapsible;
 mod optional;
 
 use std::sync::atomic::AtomicUsize;
@@ -10,9 +39,33 @@ use std::sync::atomic::AtomicUsize;
 pub use optional::Optional;
 pub use accordian::*;
 pub use alert_dialog::*;
+pub use dialog::*;
+pub use dismissable_layer::*;
+pub use focus_scope::*;
 pub use portal::*;
+pub use presence::*;
 pub use button::*;
 pub use checkbox::*;
+pub use tabs::*;
+pub use radio_group::*;
+pub use switch::*;
+pub use slider::*;
+pub use popper::*;
+pub use popover::*;
+pub use tooltip::*;
+pub use dropdown_menu::*;
+pub use context_menu::*;
+pub use select::*;
+pub use combobox::*;
+pub use listbox::*;
+pub use tree::*;
+pub use treegrid::*;
+pub use grid::*;
+pub use toggle_group::*;
+pub use toolbar::*;
+pub use breadcrumb::*;
+pub use carousel::*;
+pub use collapsible::*;
 
 use dioxus::prelude::*;
 
@@ -24,10 +77,28 @@ pub fn create_id() -> String {
         "mxa-{}",
         ID_COUNT
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-            .to_string()
     )
 }
 
+/// Wait for the given number of milliseconds
+///
+/// The timer runs in the browser, so dropping or cancelling the future stops waiting
+pub(crate) async fn sleep(ms: u64) {
+    let mut eval = document::eval(&format!("setTimeout(() => dioxus.send(true), {ms})"));
+    let _ = eval.recv::<bool>().await;
+}
+
+/// Ids of the elements matching `selector` inside of the element with the id `container`, in document order
+///
+/// Components register their parts as they mount, use this to put them in the order they are rendered in.
+pub(crate) async fn dom_order(container: &str, selector: &str) -> Vec<String> {
+    let mut eval = document::eval(&format!(r#"
+        const container = document.getElementById({container:?});
+        dioxus.send(container ? [...container.querySelectorAll(`{selector}`)].map((element) => element.id) : []);
+    "#));
+    eval.recv::<Vec<String>>().await.unwrap_or_default()
+}
+
 /// Value that is possibly a reactive signal
 #[derive(Clone, PartialEq, strum::EnumIs)]
 pub enum MaybeSignal<T: 'static> {
@@ -0,0 +1,618 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::{create_id, dom_order, roving_focus::RovingFocus, typeahead::typeahead, Optional, OptionalMaybeSignal, Orientation};
+
+/// Selector matching the options of a listbox
+const OPTIONS: &str = "[data-meek-listbox-option]";
+/// Selector matching the options of a listbox that can be focused
+const OPTION_SELECTOR: &str = "[data-meek-listbox-option]:not([data-disabled])";
+
+/// Whether a listbox selects one or many options
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum ListboxType {
+    #[default]
+    Single,
+    Multiple,
+}
+
+impl<A: AsRef<str>> From<A> for ListboxType {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "single" => Self::Single,
+            "multiple" => Self::Multiple,
+            other => panic!("unknown listbox type: '{other}'")
+        }
+    }
+}
+
+/// Handles the contextual state of a listbox
+#[derive(Debug)]
+struct ListboxState {
+    id: String,
+    pub typ: ListboxType,
+    pub disabled: bool,
+
+    focus: RovingFocus,
+    /// Option that currently has focus
+    focused: Option<String>,
+    /// Option where range selections start, the last option that was toggled
+    anchor: Option<String>,
+    /// Values of the options by the id of their element, used for typeahead
+    ids: HashMap<String, String>,
+    /// Whether the options are about to be sorted in the order they are rendered
+    ordering: bool,
+
+    current: Signal<Vec<String>>,
+    onchange: Option<EventHandler<Vec<String>>>,
+}
+
+impl ListboxState {
+    /// Limit the selected options based on the listbox type
+    fn limit(typ: ListboxType, values: Vec<String>) -> Vec<String> {
+        if typ.is_multiple() {
+            values
+        } else {
+            values.into_iter().take(1).collect()
+        }
+    }
+
+    /// Replace the selected options without notifying `onchange`
+    fn sync(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.current.peek() != values {
+            self.current.set(values);
+        }
+    }
+
+    /// Check if an option is selected
+    fn is_selected(&self, value: impl AsRef<str>) -> bool {
+        self.current.read().iter().any(|current| current == value.as_ref())
+    }
+
+    /// Whether the option is the one that is reached when tabbing into the listbox
+    fn is_tab_stop(&self, value: &str) -> bool {
+        if let Some(focused) = self.focused.as_deref() {
+            return focused == value;
+        }
+        let current = self.current.read();
+        let selected = self.focus.keys().find(|key| current.iter().any(|current| current == key));
+        match selected {
+            Some(selected) => selected == value,
+            None => self.focus.first() == Some(value),
+        }
+    }
+
+    /// Replace the selection, which is kept in the order of the options
+    fn set(&mut self, values: Vec<String>) {
+        let mut values = Self::limit(self.typ, values);
+        values.sort_by_key(|value| self.focus.position(value).unwrap_or(usize::MAX));
+        values.dedup();
+        if *self.current.peek() != values {
+            self.current.set(values);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(self.current.peek().clone());
+            }
+        }
+    }
+
+    /// Select a single option, or toggle it when multiple options can be selected
+    fn toggle(&mut self, value: &str) {
+        self.anchor = Some(value.to_string());
+        if self.typ.is_single() {
+            self.set(vec![value.to_string()]);
+        } else if self.is_selected(value) {
+            let values = self.current.peek().iter().filter(|current| *current != value).cloned().collect();
+            self.set(values);
+        } else {
+            let mut values = self.current.peek().clone();
+            values.push(value.to_string());
+            self.set(values);
+        }
+    }
+
+    /// Move the state of an option over to its new value
+    fn rename(&mut self, from: &str, to: &str) {
+        self.focus.rename_item(from, to);
+        for value in [&mut self.focused, &mut self.anchor].into_iter().flatten() {
+            if value == from {
+                *value = to.to_string();
+            }
+        }
+    }
+
+    /// Select the options from the anchor to the given option
+    fn select_range(&mut self, value: &str) {
+        let anchor = self.anchor.clone().unwrap_or_else(|| value.to_string());
+        let values = self.focus.range(anchor, value);
+        self.set(values);
+    }
+
+    /// Select every option, or unselect them when they are all selected already
+    fn toggle_all(&mut self) {
+        let all: Vec<String> = self.focus.first()
+            .zip(self.focus.last())
+            .map(|(first, last)| self.focus.range(first, last))
+            .unwrap_or_default();
+        if all.iter().all(|value| self.is_selected(value)) {
+            self.set(Vec::new());
+        } else {
+            self.set(all);
+        }
+    }
+}
+
+/// Listbox
+///
+/// A list of options where one or more can be selected.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the first selected option, or the first option if none are selected.
+/// - `<ArrowDown>`: Moves focus to the next option, and selects it when a single option can be selected.
+/// - `<ArrowUp>`: Moves focus to the previous option, and selects it when a single option can be selected.
+/// - `<Home>`: Moves focus to the first option.
+/// - `<End>`: Moves focus to the last option.
+/// - Typing a character moves focus to the next option whose text starts with the typed characters.
+///
+/// When multiple options can be selected:
+///
+/// - `<Space>`: Toggles the focused option.
+/// - `<Shift+ArrowDown>`: Moves focus to the next option and selects the options from the last toggled one.
+/// - `<Shift+ArrowUp>`: Moves focus to the previous option and selects the options from the last toggled one.
+/// - `<Shift+Space>`: Selects the options from the last toggled one to the focused one.
+/// - `<Ctrl+Shift+Home>`: Selects the options from the focused one to the first one.
+/// - `<Ctrl+Shift+End>`: Selects the options from the focused one to the last one.
+/// - `<Ctrl+A>`: Selects every option, or unselects them when they are all selected.
+#[component]
+pub fn Listbox(
+    /// Selected option(s)
+    ///
+    /// When a signal is passed it is kept in sync with the selected options
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default option(s) to select
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the selected options change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether one or many options can be selected
+    #[props(into, default)]
+    r#type: ListboxType,
+    /// Direction of the listbox. Defaults to `vertical`
+    orientation: Option<Orientation>,
+    /// Whether keyboard navigation wraps around from the last option to the first and vice versa. Defaults to `false`
+    r#loop: Option<bool>,
+    /// Whether every option in the listbox is disabled
+    disabled: Option<bool>,
+    /// User defined ID of the listbox
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the listbox `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let orientation = orientation.unwrap_or(Orientation::Vertical);
+    let current = value.clone().as_signal(ListboxState::limit(r#type, default.unwrap_or_default()));
+    let mut state = use_context_provider(|| Signal::new(ListboxState {
+        id: id.unwrap_or(create_id()),
+        typ: r#type,
+        disabled: disabled.unwrap_or_default(),
+        focus: RovingFocus::new(Some(orientation), r#loop.unwrap_or_default()),
+        focused: None,
+        anchor: None,
+        ids: HashMap::new(),
+        ordering: false,
+        current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|orientation, r#loop, disabled| {
+        let mut state = state.write();
+        state.focus.orientation = Some(orientation);
+        state.focus.looped = r#loop.unwrap_or_default();
+        state.disabled = disabled.unwrap_or_default();
+    }));
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        let Some(focused) = state.read().focused.clone() else {
+            return;
+        };
+        let (multiple, disabled) = {
+            let state = state.read();
+            (state.typ.is_multiple(), state.disabled)
+        };
+        if disabled {
+            return;
+        }
+
+        let key = evt.key();
+        let modifiers = evt.modifiers();
+        let ctrl = modifiers.ctrl() || modifiers.meta();
+        match key {
+            Key::Character(ref character) if multiple && ctrl && character.eq_ignore_ascii_case("a") => {
+                evt.prevent_default();
+                state.write().toggle_all();
+            }
+            Key::Character(ref character) if character == " " => {
+                evt.prevent_default();
+                let mut state = state.write();
+                if multiple && modifiers.shift() {
+                    state.select_range(&focused);
+                } else {
+                    state.toggle(&focused);
+                }
+            }
+            Key::Character(ref character) if character.chars().count() == 1 && !ctrl && !modifiers.alt() => {
+                let (id, current) = {
+                    let state = state.read();
+                    let current = state.ids.iter().find(|(_, value)| **value == focused).map(|(id, _)| id.clone());
+                    (state.id.clone(), current)
+                };
+                let Some(target) = typeahead(&id, OPTION_SELECTOR, character, current.as_deref()).await else {
+                    return;
+                };
+                let target = {
+                    let state = state.read();
+                    state.ids.get(&target).and_then(|value| Some((value.clone(), state.focus.get(value)?)))
+                };
+                if let Some((value, data)) = target {
+                    if !multiple {
+                        state.write().toggle(&value);
+                    }
+                    let _ = data.set_focus(true).await;
+                }
+            }
+            _ => {
+                let target = state.read().focus.navigate(&focused, &evt);
+                let Some((target, data)) = target else {
+                    return;
+                };
+                {
+                    let mut state = state.write();
+                    if !multiple {
+                        state.toggle(&target);
+                    } else if modifiers.shift() && ctrl && matches!(key, Key::Home | Key::End) {
+                        state.anchor = Some(focused.clone());
+                        state.select_range(&target);
+                    } else if modifiers.shift() && !matches!(key, Key::Home | Key::End) {
+                        if state.anchor.is_none() {
+                            state.anchor = Some(focused.clone());
+                        }
+                        state.select_range(&target);
+                    }
+                }
+                let _ = data.set_focus(true).await;
+            }
+        }
+    };
+
+    let listbox = state.read();
+
+    rsx! {
+        div {
+            role: "listbox",
+            id: listbox.id.as_str(),
+            aria_multiselectable: listbox.typ.is_multiple(),
+            aria_orientation: orientation,
+            aria_disabled: listbox.disabled,
+            "data-orientation": orientation,
+            "data-disabled": listbox.disabled,
+
+            onkeydown: onkeydown,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Sort the options in the order they are rendered, which can differ from the order they mounted in
+///
+/// Options that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<ListboxState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.id.clone()
+        };
+        let order = dom_order(&id, OPTIONS).await;
+        let order: Vec<String> = {
+            let state = state.peek();
+            order.iter().filter_map(|id| state.ids.get(id).cloned()).collect()
+        };
+        if !state.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            state.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Shares whether the option that contains a `ListboxOptionIndicator` is selected
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ListboxOptionState {
+    selected: Memo<bool>,
+}
+
+/// An option in the listbox
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// Clicking selects the option, or toggles it when multiple options can be selected. Hold `<Shift>` to select the
+/// options from the last toggled one to the clicked one.
+#[component]
+pub fn ListboxOption(
+    /// The unique value of the option
+    ///
+    /// This is what is returned when the `onchange` event is run
+    #[props(into)]
+    value: String,
+    /// Whether the option is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead. Defaults to the text content of the option
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the option `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ListboxState>>();
+    let disabled = state.read().disabled || disabled.unwrap_or_default();
+
+    let id = use_hook(create_id);
+    let value = use_memo(use_reactive!(|value| value));
+    let mut registered = use_signal(|| None::<String>);
+    let selected = use_memo(move || state.read().is_selected(value.read().as_str()));
+    use_context_provider(|| ListboxOptionState { selected });
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            let value = value();
+            let mut state = state.write();
+            if let Some(previous) = registered.peek().clone().filter(|previous| *previous != value) {
+                state.rename(&previous, &value);
+            }
+            state.ids.insert(id.clone(), value.clone());
+            registered.set(Some(value));
+        }
+    });
+
+    use_effect(use_reactive!(|disabled| {
+        state.write().focus.set_disabled(value.peek().as_str(), disabled);
+    }));
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.focus.remove_item(value.peek().as_str());
+                state.ids.remove(&id);
+            }
+        }
+    });
+
+    let tab_stop = state.read().is_tab_stop(&value.read());
+
+    rsx! {
+        div {
+            role: "option",
+            id: id.as_str(),
+            tabindex: if tab_stop && !disabled { 0 } else { -1 },
+            aria_selected: selected(),
+            aria_disabled: disabled,
+            "data-meek-listbox-option": true,
+            "data-meek-text-value": text_value,
+            "data-state": if selected() { "checked" } else { "unchecked" },
+            "data-disabled": disabled,
+
+            onfocus: move |_| state.write().focused = Some(value.peek().clone()),
+            onclick: move |evt| {
+                if disabled {
+                    return;
+                }
+                let mut state = state.write();
+                if state.typ.is_multiple() && evt.modifiers().shift() {
+                    state.select_range(&value.peek());
+                } else {
+                    state.toggle(&value.peek());
+                }
+            },
+            onmounted: move |v: Event<MountedData>| {
+                state.write().focus.add_item(value.read().as_str(), v.data());
+                schedule_order(state);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Renders when the option is selected. Must live inside `ListboxOption`
+#[component]
+pub fn ListboxOptionIndicator(
+    /// Remaining attributes to add to the indicator `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let option = use_context::<ListboxOptionState>();
+
+    rsx! {
+        if (option.selected)() {
+            span {
+                aria_hidden: true,
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Shares the id of the label of a group
+#[derive(Debug, Clone, PartialEq)]
+struct ListboxGroupState {
+    label_id: String,
+}
+
+/// Groups multiple options together
+///
+/// Keyboard navigation and range selection move across groups in the order the options are rendered.
+#[component]
+pub fn ListboxGroup(
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let group = use_context_provider(|| ListboxGroupState { label_id: create_id() });
+
+    rsx! {
+        div {
+            role: "group",
+            aria_labelledby: group.label_id,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A label for a group of options. It can't be focused with the arrow keys
+#[component]
+pub fn ListboxGroupLabel(
+    /// Remaining attributes to add to the label `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let group = try_use_context::<ListboxGroupState>();
+
+    rsx! {
+        div {
+            id: group.map(|group| group.label_id),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// Run `test` with a listbox state of the given type with the options `a` to `e`, where `d` is disabled
+    fn with_state(typ: ListboxType, values: Vec<&str>, test: impl FnOnce(&mut ListboxState)) {
+        let dom = VirtualDom::prebuilt(VNode::empty);
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| {
+            let mut focus = RovingFocus::new(Some(Orientation::Vertical), false);
+            for key in ["a", "b", "c", "d", "e"] {
+                focus.add_item(key, Rc::new(MountedData::new(())));
+            }
+            focus.set_disabled("d", true);
+
+            let mut state = ListboxState {
+                id: create_id(),
+                typ,
+                disabled: false,
+                focus,
+                focused: None,
+                anchor: None,
+                ids: HashMap::new(),
+                ordering: false,
+                current: Signal::new(values.into_iter().map(String::from).collect()),
+                onchange: None,
+            };
+            test(&mut state);
+        }));
+    }
+
+    fn values(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn set_keeps_selection_in_option_order() {
+        with_state(ListboxType::Multiple, vec![], |state| {
+            state.set(values(&["c", "a", "c", "x"]));
+            assert_eq!(*state.current.peek(), values(&["a", "c", "x"]));
+        });
+    }
+
+    #[test]
+    fn set_limits_single_selection() {
+        with_state(ListboxType::Single, vec!["a"], |state| {
+            state.set(values(&["c", "a"]));
+            assert_eq!(*state.current.peek(), values(&["c"]));
+        });
+    }
+
+    #[test]
+    fn select_range_selects_from_anchor_skipping_disabled() {
+        with_state(ListboxType::Multiple, vec![], |state| {
+            state.toggle("e");
+            state.select_range("b");
+            assert_eq!(*state.current.peek(), values(&["b", "c", "e"]));
+        });
+    }
+
+    #[test]
+    fn select_range_without_anchor_selects_option() {
+        with_state(ListboxType::Multiple, vec![], |state| {
+            state.select_range("c");
+            assert_eq!(*state.current.peek(), values(&["c"]));
+        });
+    }
+
+    #[test]
+    fn toggle_all_selects_enabled_options_then_clears() {
+        with_state(ListboxType::Multiple, vec!["b"], |state| {
+            state.toggle_all();
+            assert_eq!(*state.current.peek(), values(&["a", "b", "c", "e"]));
+            state.toggle_all();
+            assert!(state.current.peek().is_empty());
+        });
+    }
+}
@@ -0,0 +1,1256 @@
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    dom_order,
+    focus_scope::use_focus_scope,
+    popper::{render_arrow, use_popper, Placement, PopperAnchor, PopperContext, PopperOptions},
+    presence::use_presence,
+    roving_focus::RovingFocus,
+    sleep,
+    typeahead::typeahead,
+    Align,
+    FocusScopeEvent,
+    OptionalMaybeSignal,
+    Orientation,
+    Provider,
+    Side,
+    Teleport,
+};
+
+/// Selector matching the items of a menu
+const ITEMS: &str = "[data-meek-menu-item]";
+/// Selector matching the items of a menu that can receive focus
+const ITEM_SELECTOR: &str = "[data-meek-menu-item]:not([data-disabled])";
+
+/// How long the pointer grace area towards a submenu is kept
+const GRACE_DURATION: u64 = 300;
+
+/// Event passed to the `onselect` handler of a menu item
+///
+/// Call `prevent_default` to keep the menu open after the item is selected.
+#[derive(Debug, Clone, Default)]
+pub struct MenuSelectEvent {
+    prevented: Rc<Cell<bool>>,
+}
+
+impl MenuSelectEvent {
+    /// Keep the menu open
+    pub fn prevent_default(&self) {
+        self.prevented.set(true);
+    }
+
+    /// Whether `prevent_default` was called
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+}
+
+impl PartialEq for MenuSelectEvent {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.prevented, &other.prevented)
+    }
+}
+
+/// Which item receives focus when a menu opens
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub(crate) enum MenuEntry {
+    /// Opened with a pointer, the content itself is focused
+    #[default]
+    Pointer,
+    First,
+    Last,
+}
+
+/// Handles the contextual state of the root of a menu
+///
+/// This context is shared by `DropdownMenu` and `ContextMenu`
+#[derive(Debug)]
+pub(crate) struct MenuRootState {
+    pub id: String,
+    pub entry: MenuEntry,
+    /// Viewport coordinates the content is positioned at, set by `ContextMenu`
+    pub point: Option<(f64, f64)>,
+
+    pub open: Signal<bool>,
+    pub onchange: Option<EventHandler<bool>>,
+}
+
+impl MenuRootState {
+    pub fn new(id: Option<String>, open: Signal<bool>, onchange: Option<EventHandler<bool>>) -> Self {
+        Self {
+            id: id.unwrap_or(create_id()),
+            entry: MenuEntry::Pointer,
+            point: None,
+            open,
+            onchange,
+        }
+    }
+
+    /// Get the formatted trigger id
+    pub fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    pub fn set_open(&mut self, open: bool) {
+        if *self.open.peek() != open {
+            self.open.set(open);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(open);
+            }
+        }
+    }
+}
+
+/// Handles the contextual state of a submenu
+#[derive(Debug)]
+pub(crate) struct MenuSubState {
+    id: String,
+    entry: MenuEntry,
+    /// The level that contains the sub trigger
+    parent: Signal<MenuLevel>,
+    trigger: Option<Rc<MountedData>>,
+    content: Option<Rc<MountedData>>,
+
+    open: Signal<bool>,
+    onchange: Option<EventHandler<bool>>,
+}
+
+impl MenuSubState {
+    fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    fn set_open(&mut self, open: bool, entry: MenuEntry) {
+        self.entry = entry;
+        if *self.open.peek() != open {
+            self.open.set(open);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(open);
+            }
+        }
+    }
+}
+
+/// Handles the contextual state of the content of a menu or submenu
+#[derive(Debug)]
+pub(crate) struct MenuLevel {
+    id: String,
+    focus: RovingFocus,
+    /// Item that currently has focus
+    focused: Option<String>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+    content: Option<Rc<MountedData>>,
+
+    /// Area between the pointer and an open submenu where moving the pointer does not highlight other items
+    grace: Option<Vec<(f64, f64)>>,
+    grace_timer: Option<Task>,
+}
+
+impl MenuLevel {
+    fn is_highlighted(&self, id: &str) -> bool {
+        self.focused.as_deref() == Some(id)
+    }
+
+    /// Whether the pointer is moving towards an open submenu
+    fn is_in_grace(&self, x: f64, y: f64) -> bool {
+        self.grace.as_ref().is_some_and(|polygon| contains_point(polygon, x, y))
+    }
+
+    fn clear_grace(&mut self) {
+        self.grace = None;
+        if let Some(timer) = self.grace_timer.take() {
+            timer.cancel();
+        }
+    }
+}
+
+/// Whether the point is inside of the polygon
+fn contains_point(polygon: &[(f64, f64)], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut previous = polygon.len().saturating_sub(1);
+    for (current, (xi, yi)) in polygon.iter().enumerate() {
+        let (xj, yj) = polygon[previous];
+        if (yi > &y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Move focus into the content with the given `id` once it has rendered
+fn focus_content(id: &str, entry: MenuEntry) {
+    let target = match entry {
+        MenuEntry::Pointer => "content",
+        MenuEntry::First => "items[0] ?? content",
+        MenuEntry::Last => "items[items.length - 1] ?? content",
+    };
+    document::eval(&format!(r#"
+        requestAnimationFrame(() => {{
+            const content = document.getElementById("{id}");
+            if (!content) return;
+            const items = [...content.querySelectorAll(`{ITEM_SELECTOR}`)]
+                .filter((item) => item.parentElement.closest('[role="menu"]') === content);
+            ({target}).focus({{ preventScroll: true }});
+        }});
+    "#));
+}
+
+/// Run the `onselect` handler of an item and close the menu unless it was prevented
+fn select(mut root: Signal<MenuRootState>, onselect: Option<EventHandler<MenuSelectEvent>>) {
+    let event = MenuSelectEvent::default();
+    if let Some(onselect) = onselect.as_ref() {
+        onselect.call(event.clone());
+    }
+    if !event.default_prevented() {
+        root.write().set_open(false);
+    }
+}
+
+/// Options for rendering the content of a menu or submenu
+pub(crate) struct MenuContentOptions {
+    pub popper: Memo<PopperOptions>,
+    /// What the content is positioned against
+    pub anchor: Memo<PopperAnchor>,
+    /// Ids of elements outside of the root content that do not close it when interacted with
+    ///
+    /// Submenus always use their trigger
+    pub branches: Vec<String>,
+    pub looped: bool,
+    pub force_mount: bool,
+    pub handlers: DismissableLayerHandlers,
+    pub oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+}
+
+/// Render the content of a menu or, when inside of a `MenuSub`, a submenu
+///
+/// Must be called from a component rendered inside of the root of a menu
+pub(crate) fn render_menu_content(content: MenuContentOptions, mut attrs: Vec<Attribute>, children: Element) -> Element {
+    let MenuContentOptions { popper: options, anchor, branches, looped, force_mount, handlers, oncloseautofocus } = content;
+    let mut root = use_context::<Signal<MenuRootState>>();
+    let sub = try_use_context::<Signal<MenuSubState>>();
+
+    let id = use_hook(|| match sub {
+        Some(sub) => sub.peek().id.clone(),
+        None => root.peek().id.clone(),
+    });
+    let mut level = use_context_provider(|| Signal::new(MenuLevel {
+        id: id.clone(),
+        focus: RovingFocus::new(Some(Orientation::Vertical), looped),
+        focused: None,
+        ordering: false,
+        content: None,
+        grace: None,
+        grace_timer: None,
+    }));
+    use_effect(use_reactive!(|looped| level.write().focus.looped = looped));
+
+    let content_id = use_memo({
+        let id = id.clone();
+        move || id.clone()
+    });
+    let open = use_memo(move || match sub {
+        Some(sub) => *sub.read().open.read(),
+        None => *root.read().open.read(),
+    });
+    let present = use_memo(use_reactive!(|force_mount| force_mount || open()));
+    let is_present = use_presence(content_id, present);
+
+    // The content may be teleported, so wait until it is in the DOM before managing it
+    let mut mounted = use_signal(|| false);
+    use_effect(move || if !is_present() { mounted.set(false) });
+    let active = use_memo(move || open() && mounted());
+
+    // Focus is restored to the trigger when the root content closes, submenus move focus themselves
+    let is_root = sub.is_none();
+    let scoped = use_memo(move || is_root && active());
+    let unscoped = use_memo(|| false);
+    let onmountautofocus = use_callback({
+        let id = id.clone();
+        move |evt: FocusScopeEvent| {
+            evt.prevent_default();
+            focus_content(&id, root.peek().entry);
+        }
+    });
+    use_focus_scope(id.clone(), scoped, unscoped, unscoped, Some(onmountautofocus), oncloseautofocus);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            if let Some(sub) = sub {
+                let entry = sub.peek().entry;
+                if active() && !entry.is_pointer() {
+                    focus_content(&id, entry);
+                }
+            }
+        }
+    });
+
+    let ondismiss = use_callback(move |_| match sub {
+        Some(mut sub) => sub.write().set_open(false, MenuEntry::Pointer),
+        None => root.write().set_open(false),
+    });
+    // `<Escape>` only closes a submenu, so focus goes back to the item that opened it
+    let onescapekeydown = use_callback(move |_| {
+        if let Some(trigger) = sub.and_then(|sub| sub.peek().trigger.clone()) {
+            spawn(async move {
+                let _ = trigger.set_focus(true).await;
+            });
+        }
+    });
+
+    let mut handlers = handlers;
+    handlers.ondismiss = Some(ondismiss);
+    let branches = match sub {
+        Some(sub) => {
+            handlers.onescapekeydown = Some(onescapekeydown);
+            vec![sub.peek().trigger_id()]
+        }
+        None => branches,
+    };
+    use_dismissable_layer(id.clone(), active, branches, handlers);
+
+    let popper = use_memo({
+        let id = id.clone();
+        move || format!("{id}:popper")
+    });
+    let tracking = use_memo(move || is_present() && mounted());
+    let placement = use_popper(anchor, popper, tracking, options);
+    use_context_provider(|| PopperContext { placement });
+
+    let placed = *placement.read();
+    Placement::push_attributes(placed.as_ref(), &mut attrs);
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        // Keep parent menus from handling the keys of their submenus
+        evt.stop_propagation();
+
+        let key = evt.key();
+        let modifiers = evt.modifiers();
+        match key {
+            // Focus stays inside of the menu until it is closed
+            Key::Tab => evt.prevent_default(),
+            Key::ArrowLeft if sub.is_some() => {
+                evt.prevent_default();
+                if let Some(mut sub) = sub {
+                    let trigger = sub.peek().trigger.clone();
+                    sub.write().set_open(false, MenuEntry::Pointer);
+                    if let Some(trigger) = trigger {
+                        let _ = trigger.set_focus(true).await;
+                    }
+                }
+            }
+            Key::Character(ref character) if character != " "
+                && character.chars().count() == 1
+                && !modifiers.ctrl()
+                && !modifiers.alt()
+                && !modifiers.meta() =>
+            {
+                let (id, current) = {
+                    let level = level.read();
+                    (level.id.clone(), level.focused.clone())
+                };
+                if let Some(target) = typeahead(&id, ITEM_SELECTOR, character, current.as_deref()).await {
+                    let data = level.read().focus.get(&target);
+                    if let Some(data) = data {
+                        let _ = data.set_focus(true).await;
+                    }
+                }
+            }
+            _ => {
+                let target = {
+                    let level = level.read();
+                    match level.focused.as_deref() {
+                        Some(current) => level.focus.navigate(current, &evt).map(|(_, data)| data),
+                        None => {
+                            let edge = match key {
+                                Key::ArrowDown | Key::Home => level.focus.first(),
+                                Key::ArrowUp | Key::End => level.focus.last(),
+                                _ => None,
+                            };
+                            edge.and_then(|key| level.focus.get(key))
+                        }
+                    }
+                };
+                if let Some(data) = target {
+                    evt.prevent_default();
+                    let _ = data.set_focus(true).await;
+                }
+            }
+        }
+    };
+
+    let side = placed.map(|p| p.side).unwrap_or(options.read().side);
+    let align = placed.map(|p| p.align).unwrap_or(options.read().align);
+
+    rsx! {
+        if is_present() {
+            div {
+                id: popper,
+                position: "fixed",
+                left: "0px",
+                top: "0px",
+                min_width: "max-content",
+                transform: Placement::transform(placed.as_ref()),
+
+                div {
+                    role: "menu",
+                    id: id.as_str(),
+                    tabindex: -1,
+                    aria_orientation: "vertical",
+                    aria_labelledby: match sub {
+                        Some(sub) => sub.read().trigger_id(),
+                        None => root.read().trigger_id(),
+                    },
+                    "data-state": if open() { "open" } else { "closed" },
+                    "data-side": side,
+                    "data-align": align,
+
+                    onkeydown: onkeydown,
+                    onpointerenter: move |_| {
+                        // The pointer made it into the submenu
+                        if let Some(sub) = sub {
+                            let mut parent = sub.peek().parent;
+                            parent.write().clear_grace();
+                        }
+                    },
+                    onmounted: move |v: Event<MountedData>| {
+                        let data = v.data();
+                        level.write().content = Some(data.clone());
+                        if let Some(mut sub) = sub {
+                            sub.write().content = Some(data);
+                        }
+                        mounted.set(true);
+                    },
+
+                    ..attrs,
+
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/// The handle of a focusable item in the content of a menu
+#[derive(Clone, Copy)]
+struct MenuItemHandle {
+    id: Signal<String>,
+    level: Signal<MenuLevel>,
+}
+
+impl MenuItemHandle {
+    fn is_highlighted(&self) -> bool {
+        self.level.read().is_highlighted(&self.id.read())
+    }
+
+    fn onfocus(&mut self) {
+        self.level.write().focused = Some(self.id.peek().clone());
+    }
+
+    fn onblur(&mut self) {
+        let mut level = self.level.write();
+        if level.is_highlighted(&self.id.peek()) {
+            level.focused = None;
+        }
+    }
+
+    /// Highlight the item when the pointer moves over it, unless it is moving towards a submenu
+    async fn onpointermove(mut self, evt: Event<PointerData>, disabled: bool) {
+        if evt.pointer_type() != "mouse" {
+            return;
+        }
+        let point = evt.client_coordinates();
+        if self.level.read().is_in_grace(point.x, point.y) {
+            return;
+        }
+        self.level.write().clear_grace();
+
+        let data = {
+            let level = self.level.read();
+            if disabled || level.is_highlighted(&self.id.read()) {
+                None
+            } else {
+                level.focus.get(self.id.read().as_str())
+            }
+        };
+        if let Some(data) = data {
+            let _ = data.set_focus(true).await;
+        }
+    }
+
+    /// Register the mounted item with the roving focus of its menu
+    fn onmounted(self, data: Rc<MountedData>) {
+        let mut level = self.level;
+        level.write().focus.add_item(self.id.read().as_str(), data);
+        schedule_order(level);
+    }
+
+    /// Remove the highlight by focusing the content when the pointer leaves the item
+    async fn onpointerleave(self, evt: Event<PointerData>) {
+        if evt.pointer_type() != "mouse" {
+            return;
+        }
+        let point = evt.client_coordinates();
+        let content = {
+            let level = self.level.read();
+            if level.is_in_grace(point.x, point.y) { None } else { level.content.clone() }
+        };
+        if let Some(content) = content {
+            let _ = content.set_focus(true).await;
+        }
+    }
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut level: Signal<MenuLevel>) {
+    if std::mem::replace(&mut level.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut level = level.write();
+            level.ordering = false;
+            level.id.clone()
+        };
+        let order = dom_order(&id, ITEMS).await;
+        // Leave out the items of submenus that are rendered inside of the content
+        let order: Vec<String> = {
+            let level = level.peek();
+            order.into_iter().filter(|id| level.focus.position(id).is_some()).collect()
+        };
+        if !level.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            level.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Register a focusable item with the content of the menu it is rendered in
+fn use_menu_item(disabled: bool) -> MenuItemHandle {
+    let mut level = use_context::<Signal<MenuLevel>>();
+    let id = use_signal(create_id);
+
+    use_effect(use_reactive!(|disabled| {
+        level.write().focus.set_disabled(id.peek().as_str(), disabled);
+    }));
+
+    use_drop(move || {
+        if let Ok(mut level) = level.try_write() {
+            level.focus.remove_item(id.peek().as_str());
+        }
+    });
+
+    MenuItemHandle { id, level }
+}
+
+/// An item in the menu
+///
+/// # Data Attributes
+///
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Selects the item.
+/// - `<Enter>`: Selects the item.
+#[component]
+pub fn MenuItem(
+    /// Handler that is run when the item is selected with a pointer or the keyboard
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onselect: Option<EventHandler<MenuSelectEvent>>,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead. Defaults to the text content of the item
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+    let disabled = disabled.unwrap_or_default();
+    let mut item = use_menu_item(disabled);
+
+    rsx! {
+        div {
+            role: "menuitem",
+            id: item.id,
+            tabindex: -1,
+            aria_disabled: disabled,
+            "data-meek-menu-item": true,
+            "data-meek-text-value": text_value,
+            "data-highlighted": item.is_highlighted(),
+            "data-disabled": disabled,
+
+            onfocus: move |_| item.onfocus(),
+            onblur: move |_| item.onblur(),
+            onpointermove: move |evt| item.onpointermove(evt, disabled),
+            onpointerleave: move |evt| item.onpointerleave(evt),
+            onclick: move |_| if !disabled { select(root, onselect) },
+            onkeydown: move |evt| {
+                if matches!(evt.key(), Key::Enter) || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    if !disabled { select(root, onselect) }
+                }
+            },
+            onmounted: move |v: Event<MountedData>| item.onmounted(v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Groups multiple items together
+#[component]
+pub fn MenuGroup(
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            role: "group",
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A label for a group of items. It can't be focused with the arrow keys
+#[component]
+pub fn MenuLabel(
+    /// Remaining attributes to add to the label `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Visually separates items in the menu
+#[component]
+pub fn MenuSeparator(
+    /// Remaining attributes to add to the separator `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+) -> Element {
+    rsx! {
+        div {
+            role: "separator",
+            aria_orientation: "horizontal",
+
+            ..attrs,
+        }
+    }
+}
+
+/// Shares whether the item that contains a `MenuItemIndicator` is checked
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MenuItemIndicatorState {
+    checked: Memo<bool>,
+}
+
+/// An item that can be checked and unchecked
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Toggles the item.
+/// - `<Enter>`: Toggles the item.
+#[component]
+pub fn MenuCheckboxItem(
+    /// Passed state whether the item is checked
+    checked: Option<bool>,
+    /// Default checked state of the item
+    default: Option<bool>,
+    /// Event handler for when the checked state changes
+    onchange: Option<EventHandler<bool>>,
+    /// Handler that is run when the item is selected with a pointer or the keyboard
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onselect: Option<EventHandler<MenuSelectEvent>>,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead. Defaults to the text content of the item
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+    let disabled = disabled.unwrap_or_default();
+    let mut item = use_menu_item(disabled);
+
+    let mut state = use_signal(|| checked.or(default).unwrap_or_default());
+    use_effect(use_reactive!(|checked| {
+        if let Some(checked) = checked {
+            state.set(checked);
+        }
+    }));
+    let is_checked = use_memo(move || *state.read());
+    use_context_provider(|| MenuItemIndicatorState { checked: is_checked });
+
+    let mut toggle = move || {
+        if disabled {
+            return;
+        }
+        state.toggle();
+        if let Some(onchange) = onchange.as_ref() {
+            onchange.call(state());
+        }
+        select(root, onselect);
+    };
+
+    rsx! {
+        div {
+            role: "menuitemcheckbox",
+            id: item.id,
+            tabindex: -1,
+            aria_checked: state(),
+            aria_disabled: disabled,
+            "data-meek-menu-item": true,
+            "data-meek-text-value": text_value,
+            "data-state": if state() { "checked" } else { "unchecked" },
+            "data-highlighted": item.is_highlighted(),
+            "data-disabled": disabled,
+
+            onfocus: move |_| item.onfocus(),
+            onblur: move |_| item.onblur(),
+            onpointermove: move |evt| item.onpointermove(evt, disabled),
+            onpointerleave: move |evt| item.onpointerleave(evt),
+            onclick: move |_| toggle(),
+            onkeydown: move |evt| {
+                if matches!(evt.key(), Key::Enter) || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    toggle();
+                }
+            },
+            onmounted: move |v: Event<MountedData>| item.onmounted(v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Handles the contextual state of a group of radio items
+#[derive(Debug)]
+struct MenuRadioGroupState {
+    current: Signal<String>,
+    onchange: Option<EventHandler<String>>,
+}
+
+/// Groups radio items where only one of them can be checked at a time
+#[component]
+pub fn MenuRadioGroup(
+    /// The checked item
+    ///
+    /// When a signal is passed it is kept in sync with the checked item
+    #[props(into, default)]
+    value: OptionalMaybeSignal<String>,
+    /// The item that is checked by default
+    #[props(into)]
+    default: Option<String>,
+    /// Handler that is run when the checked item changes
+    onchange: Option<EventHandler<String>>,
+
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(default.unwrap_or_default());
+    let state = use_context_provider(|| Signal::new(MenuRadioGroupState { current, onchange }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            let mut current = state.peek().current;
+            if *current.peek() != value {
+                current.set(value);
+            }
+        }
+    }));
+
+    rsx! {
+        div {
+            role: "group",
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// An item in a `MenuRadioGroup` that is checked when selected
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Checks the item.
+/// - `<Enter>`: Checks the item.
+#[component]
+pub fn MenuRadioItem(
+    /// The unique value of the item in its group
+    #[props(into)]
+    value: String,
+    /// Handler that is run when the item is selected with a pointer or the keyboard
+    ///
+    /// Call `prevent_default` on the event to keep the menu open
+    onselect: Option<EventHandler<MenuSelectEvent>>,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead. Defaults to the text content of the item
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+    let group = use_context::<Signal<MenuRadioGroupState>>();
+    let disabled = disabled.unwrap_or_default();
+    let mut item = use_menu_item(disabled);
+
+    let value = use_signal(|| value);
+    let checked = use_memo(move || *group.read().current.read() == *value.read());
+    use_context_provider(|| MenuItemIndicatorState { checked });
+
+    let check = move || {
+        if disabled {
+            return;
+        }
+        if !checked() {
+            let group = group.read();
+            let mut current = group.current;
+            current.set(value.peek().clone());
+            if let Some(onchange) = group.onchange.as_ref() {
+                onchange.call(value.peek().clone());
+            }
+        }
+        select(root, onselect);
+    };
+
+    rsx! {
+        div {
+            role: "menuitemradio",
+            id: item.id,
+            tabindex: -1,
+            aria_checked: checked(),
+            aria_disabled: disabled,
+            "data-meek-menu-item": true,
+            "data-meek-text-value": text_value,
+            "data-state": if checked() { "checked" } else { "unchecked" },
+            "data-highlighted": item.is_highlighted(),
+            "data-disabled": disabled,
+
+            onfocus: move |_| item.onfocus(),
+            onblur: move |_| item.onblur(),
+            onpointermove: move |evt| item.onpointermove(evt, disabled),
+            onpointerleave: move |evt| item.onpointerleave(evt),
+            onclick: move |_| check(),
+            onkeydown: move |evt| {
+                if matches!(evt.key(), Key::Enter) || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    check();
+                }
+            },
+            onmounted: move |v: Event<MountedData>| item.onmounted(v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Renders when the parent `MenuCheckboxItem` or `MenuRadioItem` is checked
+///
+/// The indicator stays mounted until its exit animations have finished after the item is unchecked.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+#[component]
+pub fn MenuItemIndicator(
+    /// Keep the indicator mounted even when the item is unchecked
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the indicator `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let item = use_context::<MenuItemIndicatorState>();
+
+    let id = use_memo(create_id);
+    let checked = item.checked;
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || checked()));
+    let is_present = use_presence(id, present);
+
+    rsx! {
+        if is_present() {
+            span {
+                id: id,
+                "data-state": if checked() { "checked" } else { "unchecked" },
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Contains the parts of a submenu
+#[component]
+pub fn MenuSub(
+    /// Passed state whether the submenu should be open or closed
+    open: Option<bool>,
+    /// Default open state of the submenu
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+
+    children: Element,
+) -> Element {
+    let parent = use_context::<Signal<MenuLevel>>();
+    let mut current = use_signal(|| open.or(default).unwrap_or_default());
+    use_context_provider(|| Signal::new(MenuSubState {
+        id: create_id(),
+        entry: MenuEntry::Pointer,
+        parent,
+        trigger: None,
+        content: None,
+        open: current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            current.set(open);
+        }
+    }));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// An item that opens a submenu. Must live inside `MenuSub`
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Opens the submenu and focuses its first item.
+/// - `<Enter>`: Opens the submenu and focuses its first item.
+/// - `<ArrowRight>`: Opens the submenu and focuses its first item.
+#[component]
+pub fn MenuSubTrigger(
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead. Defaults to the text content of the item
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut sub = use_context::<Signal<MenuSubState>>();
+    let disabled = disabled.unwrap_or_default();
+    let mut item = use_menu_item(disabled);
+    let open = *sub.read().open.read();
+
+    let onpointermove = move |evt: Event<PointerData>| async move {
+        let is_mouse = evt.pointer_type() == "mouse";
+        item.onpointermove(evt, disabled).await;
+        if is_mouse && !disabled && !*sub.peek().open.peek() {
+            sub.write().set_open(true, MenuEntry::Pointer);
+        }
+    };
+
+    let onpointerleave = move |evt: Event<PointerData>| async move {
+        if evt.pointer_type() != "mouse" {
+            return;
+        }
+        let content = sub.peek().content.clone().filter(|_| *sub.peek().open.peek());
+        let Some(content) = content else {
+            item.onpointerleave(evt).await;
+            return;
+        };
+
+        // Keep a grace area between the pointer and the submenu so moving towards it does not highlight other items
+        let point = evt.client_coordinates();
+        let Ok(rect) = content.get_client_rect().await else { return };
+        let right = rect.min_x() >= point.x;
+        let (near, far, bleed) = if right { (rect.min_x(), rect.max_x(), -5.0) } else { (rect.max_x(), rect.min_x(), 5.0) };
+        let polygon = vec![
+            (point.x + bleed, point.y),
+            (near, rect.min_y()),
+            (far, rect.min_y()),
+            (far, rect.max_y()),
+            (near, rect.max_y()),
+        ];
+
+        let mut level = item.level;
+        let mut handle = level;
+        let mut current = level.write();
+        current.clear_grace();
+        current.grace = Some(polygon);
+        current.grace_timer = Some(spawn(async move {
+            sleep(GRACE_DURATION).await;
+            let mut level = handle.write();
+            level.grace = None;
+            level.grace_timer = None;
+        }));
+    };
+
+    let onkeydown = move |evt: Event<KeyboardData>| {
+        let key = evt.key();
+        if !(matches!(key, Key::Enter | Key::ArrowRight) || key == Key::Character(" ".to_string())) {
+            return;
+        }
+        evt.prevent_default();
+        evt.stop_propagation();
+        if disabled {
+            return;
+        }
+
+        let mut sub = sub.write();
+        if *sub.open.peek() {
+            focus_content(&sub.id, MenuEntry::First);
+        }
+        sub.set_open(true, MenuEntry::First);
+    };
+
+    rsx! {
+        div {
+            role: "menuitem",
+            id: sub.read().trigger_id(),
+            tabindex: -1,
+            aria_haspopup: "menu",
+            aria_expanded: open,
+            aria_controls: if open { Some(sub.read().id.clone()) } else { None },
+            aria_disabled: disabled,
+            "data-meek-menu-item": true,
+            "data-meek-text-value": text_value,
+            "data-state": if open { "open" } else { "closed" },
+            "data-highlighted": item.is_highlighted(),
+            "data-disabled": disabled,
+
+            onfocus: move |_| item.onfocus(),
+            onblur: move |_| item.onblur(),
+            onpointermove: onpointermove,
+            onpointerleave: onpointerleave,
+            onclick: move |_| if !disabled { sub.write().set_open(true, MenuEntry::Pointer) },
+            onkeydown: onkeydown,
+            onmounted: move |v: Event<MountedData>| {
+                let data = v.data();
+                sub.write().trigger = Some(data.clone());
+                item.onmounted(data);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The content of a submenu. Must live inside `MenuSub`
+///
+/// The content is placed to the side of its trigger and stays mounted until its exit animations have finished
+/// after it is closed.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"right"` | `"left"`
+/// - `[data-align]`: `"start"`
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowDown>`: Moves focus to the next item.
+/// - `<ArrowUp>`: Moves focus to the previous item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last item.
+/// - `<ArrowLeft>`: Closes the submenu and moves focus to its trigger.
+/// - `<Escape>`: Closes the submenu and moves focus to its trigger.
+/// - Typing a character moves focus to the next item whose text starts with the typed characters.
+#[component]
+pub fn MenuSubContent(
+    /// Distance in pixels between the trigger and the content. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Offset in pixels from the top of the trigger. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips and shifts to stay inside of the boundary. Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+    /// Whether keyboard navigation wraps around from the last item to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+    /// Keep the content mounted even when the submenu is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let sub = use_context::<Signal<MenuSubState>>();
+
+    let options = use_memo(use_reactive!(|side_offset, align_offset, avoid_collisions, collision_padding, collision_boundary| {
+        PopperOptions {
+            side: Side::Right,
+            align: Align::Start,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: 0.0,
+        }
+    }));
+    let anchor = use_memo(move || PopperAnchor::Element(sub.read().trigger_id()));
+
+    render_menu_content(
+        MenuContentOptions {
+            popper: options,
+            anchor,
+            branches: Vec::new(),
+            looped: r#loop.unwrap_or(true),
+            force_mount: force_mount.unwrap_or_default(),
+            handlers: DismissableLayerHandlers::default(),
+            oncloseautofocus: None,
+        },
+        attrs,
+        children,
+    )
+}
+
+/// Teleports the content to where the `Portal` with the same `name` is rendered
+///
+/// Use this to escape `overflow: hidden` and stacking contexts of the parent elements. Works for the content of
+/// both menus and submenus.
+#[component]
+pub fn MenuPortal(
+    /// Name of the `Portal` to render the content in
+    #[props(into)]
+    name: Option<String>,
+
+    children: Element,
+) -> Element {
+    let root = use_context::<Signal<MenuRootState>>();
+    let level = try_use_context::<Signal<MenuLevel>>();
+    let sub = try_use_context::<Signal<MenuSubState>>();
+
+    // Teleported elements lose their context, so it is provided again where they are rendered
+    let mut content = rsx! { {children} };
+    if let Some(sub) = sub {
+        content = rsx! { Provider { inherit: sub, {content} } };
+    }
+    if let Some(level) = level {
+        content = rsx! { Provider { inherit: level, {content} } };
+    }
+
+    rsx! {
+        Teleport {
+            name: name,
+            Provider {
+                inherit: root,
+                {content}
+            }
+        }
+    }
+}
+
+/// An arrow that points from the content to the trigger. Must live inside the content of a menu
+///
+/// Renders a triangle `svg` unless children are passed.
+#[component]
+pub fn MenuArrow(
+    /// Width of the arrow in pixels. Defaults to `10`
+    width: Option<f64>,
+    /// Height of the arrow in pixels. Defaults to `5`
+    height: Option<f64>,
+
+    /// Remaining attributes to add to the arrow `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_arrow(width.unwrap_or(10.0), height.unwrap_or(5.0), attrs, children)
+}
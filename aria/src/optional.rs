@@ -1,6 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
-use dioxus::prelude::{SuperFrom, SuperInto};
+use dioxus::prelude::SuperFrom;
 
 /// Mimics the builtin `Option` enum type
 /// 
@@ -36,7 +36,12 @@ impl<T> Optional<T> {
         }
     }
 
-    
+
+    /// # Safety
+    ///
+    /// Calling this method on [`Optional::None`] is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[inline]
     pub unsafe fn unwrap_unchecked(self) -> T {
         match self {
@@ -81,15 +86,13 @@ impl<T> Optional<T> {
 
     #[inline]
     pub fn take(&mut self) -> Self {
-        // FIXME replace `mem::replace` by `mem::take` when the latter is const ready
-        std::mem::replace(self, Self::default())
+        std::mem::take(self)
     }
 
     #[inline]
     pub fn take_if<F: FnOnce(&mut Self) -> bool>(&mut self, predicate: F) -> Self {
-        // FIXME replace `mem::replace` by `mem::take` when the latter is const ready
         if predicate(self) {
-            std::mem::replace(self, Self::default())
+            std::mem::take(self)
         } else {
             Self::default()
         }
@@ -106,7 +109,7 @@ impl<T> Optional<T> {
     #[inline]
     pub fn inspect<F: FnOnce(&T)>(self, provider: F) -> Self {
         if let Optional::Some(value) = self.as_ref() {
-            provider(&value)
+            provider(value)
         }
         self
     }
@@ -228,7 +231,7 @@ impl<T: Deref> Optional<T> {
     pub fn as_deref(&self) -> Optional<&T::Target> {
         match self {
             Self::None => Optional::None,
-            Self::Some(value) => Optional::Some(&*value),
+            Self::Some(value) => Optional::Some(value),
         }
     }
 }
@@ -0,0 +1,427 @@
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    focus_scope::use_focus_scope,
+    popper::{render_arrow, use_popper, Placement, PopperAnchor, PopperContext, PopperOptions},
+    presence::use_presence,
+    Align,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+    Provider,
+    Side,
+    Teleport,
+};
+
+/// Handles the contextual state of a popover
+#[derive(Debug)]
+struct PopoverState {
+    id: String,
+    pub modal: bool,
+    /// Whether a `PopoverAnchor` is used instead of the trigger to position the content
+    pub custom_anchor: bool,
+
+    open: Signal<bool>,
+    onchange: Option<EventHandler<bool>>,
+}
+
+impl PopoverState {
+    /// Get the formatted trigger id
+    fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    /// Get the formatted id of the element the content is positioned against
+    fn anchor_id(&self) -> String {
+        if self.custom_anchor { format!("{}:anchor", self.id) } else { self.trigger_id() }
+    }
+
+    /// Get the formatted id of the element that positions the content
+    fn popper_id(&self) -> String {
+        format!("{}:popper", self.id)
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if *self.open.peek() != open {
+            self.open.set(open);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(open);
+            }
+        }
+    }
+
+    fn toggle(&mut self) {
+        let open = !*self.open.peek();
+        self.set_open(open);
+    }
+}
+
+/// Popover
+///
+/// Displays rich content in a portal, triggered by a button.
+///
+/// When `modal` focus is kept inside of the content while it is open.
+#[component]
+pub fn Popover(
+    /// Passed state whether the popover should be open or closed
+    open: Option<bool>,
+    /// Default open state of the popover
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+    /// Whether focus is trapped inside of the content. Defaults to `false`
+    modal: Option<bool>,
+    /// User defined ID of the popover content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let mut current = use_signal(|| open.or(default).unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(PopoverState {
+        id: id.unwrap_or(create_id()),
+        modal: modal.unwrap_or_default(),
+        custom_anchor: false,
+        open: current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            current.set(open);
+        }
+    }));
+
+    use_effect(use_reactive!(|modal| {
+        state.write().modal = modal.unwrap_or_default();
+    }));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// The button that toggles the popover
+///
+/// By default the content is positioned against the trigger.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Opens/closes the popover.
+/// - `<Enter>`: Opens/closes the popover.
+#[component]
+pub fn PopoverTrigger(
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<PopoverState>>();
+    let open = *state.read().open.read();
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: state.read().trigger_id(),
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_haspopup: "dialog",
+            aria_expanded: open,
+            aria_controls: state.read().id.as_str(),
+
+            "data-state": if open { "open" } else { "closed" },
+
+            onclick: move |_| state.write().toggle(),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// An optional element to position the content against instead of the trigger
+#[component]
+pub fn PopoverAnchor(
+    /// Remaining attributes to add to the anchor `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<PopoverState>>();
+
+    use_effect(move || state.write().custom_anchor = true);
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.custom_anchor = false;
+        }
+    });
+
+    rsx! {
+        div {
+            // `anchor_id` points elsewhere until the effect above has run
+            id: format!("{}:anchor", state.read().id),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Teleports the content to where the `Portal` with the same `name` is rendered
+///
+/// Use this to escape `overflow: hidden` and stacking contexts of the parent elements.
+#[component]
+pub fn PopoverPortal(
+    /// Name of the `Portal` to render the content in
+    #[props(into)]
+    name: Option<String>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<PopoverState>>();
+
+    rsx! {
+        Teleport {
+            name: name,
+            // Teleported elements lose their context, so it is provided again where they are rendered
+            Provider {
+                inherit: state,
+                {children}
+            }
+        }
+    }
+}
+
+/// The component that pops out when the popover is open
+///
+/// The content is positioned against the trigger, or `PopoverAnchor`, and flips and shifts to stay inside of the
+/// collision boundary. It stays mounted until its exit animations have finished after it is closed.
+///
+/// Pressing `<Escape>` or interacting outside of the content closes the popover.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`
+///
+/// # CSS Variables
+///
+/// - `--meek-popper-available-width`: The width between the anchor and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the anchor and the edge of the boundary
+/// - `--meek-popper-anchor-width`: The width of the anchor
+/// - `--meek-popper-anchor-height`: The height of the anchor
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the anchor and arrow
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the next focusable element in the content.
+/// - `<Shift+Tab>`: Moves focus to the previous focusable element in the content.
+/// - `<Escape>`: Closes the popover and moves focus back to the trigger.
+#[component]
+pub fn PopoverContent(
+    /// The side of the anchor to place the content against. Defaults to `bottom`
+    #[props(into, default)]
+    side: Side,
+    /// Distance in pixels between the anchor and the content. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Alignment of the content along the side of the anchor. Defaults to `center`
+    #[props(into, default)]
+    align: Align,
+    /// Offset in pixels from the `align` position. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the opposite side and shifts along the anchor to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+    /// Distance in pixels between the arrow and the edges of the content. Defaults to `0`
+    arrow_padding: Option<f64>,
+
+    /// Keep the content mounted even when the popover is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when focus moves into the content after opening
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    onopenautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when focus moves back to the trigger after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the popover open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the popover open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the popover open
+    onfocusoutside: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed or focus moves outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the popover open
+    oninteractoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<PopoverState>>();
+
+    let id = use_memo(move || state.read().id.clone());
+    let open = use_memo(move || *state.read().open.read());
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || open()));
+    let is_present = use_presence(id, present);
+
+    // The content may be teleported, so wait until it is in the DOM before managing it
+    let mut mounted = use_signal(|| false);
+    use_effect(move || if !is_present() { mounted.set(false) });
+    let active = use_memo(move || open() && mounted());
+
+    let modal = use_memo(move || state.read().modal);
+    use_focus_scope(state.peek().id.clone(), active, modal, modal, onopenautofocus, oncloseautofocus);
+
+    let ondismiss = use_callback(move |_| state.write().set_open(false));
+    use_dismissable_layer(
+        state.peek().id.clone(),
+        active,
+        vec![state.peek().trigger_id()],
+        DismissableLayerHandlers {
+            onescapekeydown,
+            onpointerdownoutside,
+            onfocusoutside,
+            oninteractoutside,
+            ondismiss: Some(ondismiss),
+        },
+    );
+
+    let options = use_memo(use_reactive!(|side, side_offset, align, align_offset, avoid_collisions, collision_padding, collision_boundary, arrow_padding| {
+        PopperOptions {
+            side,
+            align,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: arrow_padding.unwrap_or_default(),
+        }
+    }));
+    let anchor = use_memo(move || PopperAnchor::Element(state.read().anchor_id()));
+    let popper = use_memo(move || state.read().popper_id());
+    let tracking = use_memo(move || is_present() && mounted());
+    let placement = use_popper(anchor, popper, tracking, options);
+    use_context_provider(|| PopperContext { placement });
+
+    let placed = *placement.read();
+    let mut attrs = attrs;
+    Placement::push_attributes(placed.as_ref(), &mut attrs);
+
+    rsx! {
+        if is_present() {
+            div {
+                id: state.read().popper_id(),
+                position: "fixed",
+                left: "0px",
+                top: "0px",
+                min_width: "max-content",
+                transform: Placement::transform(placed.as_ref()),
+
+                div {
+                    role: "dialog",
+                    id: state.read().id.as_str(),
+                    tabindex: -1,
+                    aria_modal: state.read().modal,
+                    "data-state": if open() { "open" } else { "closed" },
+                    "data-side": placed.map(|p| p.side).unwrap_or(side),
+                    "data-align": placed.map(|p| p.align).unwrap_or(align),
+
+                    onmounted: move |_| mounted.set(true),
+
+                    ..attrs,
+
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/// A button that closes the popover
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Closes the popover.
+/// - `<Enter>`: Closes the popover.
+#[component]
+pub fn PopoverClose(
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the close `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<PopoverState>>();
+
+    rsx! {
+        button {
+            r#type: "button",
+            disabled: disabled,
+            aria_disabled: disabled,
+            onclick: move |_| state.write().set_open(false),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// An arrow that points from the content to the anchor. Must live inside `PopoverContent`
+///
+/// Renders a triangle `svg` unless children are passed.
+#[component]
+pub fn PopoverArrow(
+    /// Width of the arrow in pixels. Defaults to `10`
+    width: Option<f64>,
+    /// Height of the arrow in pixels. Defaults to `5`
+    height: Option<f64>,
+
+    /// Remaining attributes to add to the arrow `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_arrow(width.unwrap_or(10.0), height.unwrap_or(5.0), attrs, children)
+}
@@ -0,0 +1,553 @@
+use dioxus::prelude::*;
+
+/// The side of the anchor that floating content is placed against
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum Side {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Whether the content is placed above or below the anchor
+    fn is_block(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+
+    /// Name of the side, which is also the CSS property of the matching edge
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Right => "right",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+        }
+    }
+}
+
+impl<A: AsRef<str>> From<A> for Side {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "top" => Self::Top,
+            "right" => Self::Right,
+            "bottom" => Self::Bottom,
+            "left" => Self::Left,
+            other => panic!("unknown side: '{other}'")
+        }
+    }
+}
+
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl IntoAttributeValue for Side {
+    fn into_value(self) -> dioxus::dioxus_core::AttributeValue {
+        dioxus::dioxus_core::AttributeValue::Text(self.to_string())
+    }
+}
+
+/// How floating content is aligned along the side of the anchor
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+impl<A: AsRef<str>> From<A> for Align {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "start" => Self::Start,
+            "center" => Self::Center,
+            "end" => Self::End,
+            other => panic!("unknown align: '{other}'")
+        }
+    }
+}
+
+impl std::fmt::Display for Align {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Start => write!(f, "start"),
+            Self::Center => write!(f, "center"),
+            Self::End => write!(f, "end"),
+        }
+    }
+}
+
+impl IntoAttributeValue for Align {
+    fn into_value(self) -> dioxus::dioxus_core::AttributeValue {
+        dioxus::dioxus_core::AttributeValue::Text(self.to_string())
+    }
+}
+
+/// A rectangle in viewport coordinates
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// What floating content is positioned against
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PopperAnchor {
+    /// The element with the given id
+    Element(String),
+    /// A point in viewport coordinates, e.g. where the pointer was pressed
+    Point(f64, f64),
+}
+
+impl PopperAnchor {
+    /// Script that evaluates to the bounding rectangle of the anchor, or nothing when it is not rendered
+    fn rect_script(&self) -> String {
+        match self {
+            Self::Element(id) => format!("document.getElementById({id:?})?.getBoundingClientRect()"),
+            Self::Point(x, y) => format!("new DOMRect({x}, {y}, 0, 0)"),
+        }
+    }
+}
+
+/// Options for placing floating content next to its anchor
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct PopperOptions {
+    pub side: Side,
+    pub align: Align,
+    /// Distance between the anchor and the content
+    pub side_offset: f64,
+    /// Offset from the `align` position
+    pub align_offset: f64,
+    /// Whether the content flips and shifts to stay inside of the boundary
+    pub avoid_collisions: bool,
+    /// Distance from the edges of the boundary where collisions are detected
+    pub collision_padding: f64,
+    /// Id of the element used as the collision boundary. Defaults to the viewport
+    pub collision_boundary: Option<String>,
+    /// Distance between the arrow and the edges of the content
+    pub arrow_padding: f64,
+}
+
+/// Sizes that are measured in the DOM to compute a placement
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Measurements {
+    pub anchor: Rect,
+    pub floating: (f64, f64),
+    pub arrow: Option<(f64, f64)>,
+    pub boundary: Rect,
+}
+
+impl Measurements {
+    /// Read the measurements from the flat list sent by the measuring script
+    fn from_slice(values: &[f64]) -> Option<Self> {
+        let [ax, ay, aw, ah, fw, fh, rw, rh, bx, by, bw, bh] = *values else {
+            return None;
+        };
+        Some(Self {
+            anchor: Rect { x: ax, y: ay, width: aw, height: ah },
+            floating: (fw, fh),
+            arrow: (rw > 0.0 || rh > 0.0).then_some((rw, rh)),
+            boundary: Rect { x: bx, y: by, width: bw, height: bh },
+        })
+    }
+}
+
+/// Where the floating content is placed
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Placement {
+    pub x: f64,
+    pub y: f64,
+    /// The side after flipping to avoid collisions
+    pub side: Side,
+    pub align: Align,
+    pub available_width: f64,
+    pub available_height: f64,
+    pub anchor_width: f64,
+    pub anchor_height: f64,
+    /// Offset of the arrow along the side of the content
+    pub arrow: Option<f64>,
+    pub transform_origin: (f64, f64),
+}
+
+impl Placement {
+    /// Add the `--meek-popper-*` CSS variables to the attributes of the content
+    pub(crate) fn push_attributes(placement: Option<&Self>, attrs: &mut Vec<Attribute>) {
+        let Some(placement) = placement else { return };
+        let (origin_x, origin_y) = placement.transform_origin;
+        let variables = [
+            ("--meek-popper-available-width", format!("{}px", placement.available_width)),
+            ("--meek-popper-available-height", format!("{}px", placement.available_height)),
+            ("--meek-popper-anchor-width", format!("{}px", placement.anchor_width)),
+            ("--meek-popper-anchor-height", format!("{}px", placement.anchor_height)),
+            ("--meek-popper-transform-origin", format!("{origin_x}px {origin_y}px")),
+        ];
+        for (name, value) in variables {
+            attrs.push(Attribute::new(name, value, Some("style"), false));
+        }
+    }
+
+    /// CSS transform that moves the content wrapper into place
+    ///
+    /// Content that has not been measured yet is moved off screen
+    pub(crate) fn transform(placement: Option<&Self>) -> String {
+        match placement {
+            Some(placement) => format!("translate({}px, {}px)", placement.x.round(), placement.y.round()),
+            None => "translate(0, -200%)".to_string(),
+        }
+    }
+}
+
+/// Compute where the floating content should be placed relative to its anchor
+pub(crate) fn compute_placement(measurements: &Measurements, options: &PopperOptions) -> Placement {
+    let Measurements { anchor, floating: (width, height), arrow, boundary } = *measurements;
+    let padding = options.collision_padding;
+    let boundary = Rect {
+        x: boundary.x + padding,
+        y: boundary.y + padding,
+        width: (boundary.width - padding * 2.0).max(0.0),
+        height: (boundary.height - padding * 2.0).max(0.0),
+    };
+    let (arrow_width, arrow_height) = arrow.unwrap_or_default();
+    let offset = options.side_offset + arrow_height;
+
+    let space = |side: Side| match side {
+        Side::Top => anchor.y - boundary.y,
+        Side::Right => boundary.right() - anchor.right(),
+        Side::Bottom => boundary.bottom() - anchor.bottom(),
+        Side::Left => anchor.x - boundary.x,
+    };
+    let size = |side: Side| if side.is_block() { height } else { width };
+
+    // Flip to the opposite side when there is not enough room and the opposite side has more
+    let mut side = options.side;
+    if options.avoid_collisions && space(side) < size(side) + offset && space(side.opposite()) > space(side) {
+        side = side.opposite();
+    }
+
+    let (anchor_start, anchor_length, length, boundary_start, boundary_end) = if side.is_block() {
+        (anchor.x, anchor.width, width, boundary.x, boundary.right())
+    } else {
+        (anchor.y, anchor.height, height, boundary.y, boundary.bottom())
+    };
+
+    let mut cross = match options.align {
+        Align::Start => anchor_start + options.align_offset,
+        Align::Center => anchor_start + (anchor_length - length) / 2.0 + options.align_offset,
+        Align::End => anchor_start + anchor_length - length - options.align_offset,
+    };
+    // Shift along the side of the anchor to stay inside of the boundary
+    if options.avoid_collisions {
+        cross = cross.clamp(boundary_start, (boundary_end - length).max(boundary_start));
+    }
+
+    let main = match side {
+        Side::Top => anchor.y - height - offset,
+        Side::Right => anchor.right() + offset,
+        Side::Bottom => anchor.bottom() + offset,
+        Side::Left => anchor.x - width - offset,
+    };
+    let (x, y) = if side.is_block() { (cross, main) } else { (main, cross) };
+
+    // Point the arrow at the center of the anchor while keeping it inside of the content
+    let arrow = arrow.map(|_| {
+        let min = options.arrow_padding;
+        let max = (length - arrow_width - options.arrow_padding).max(min);
+        (anchor_start + anchor_length / 2.0 - cross - arrow_width / 2.0).clamp(min, max)
+    });
+
+    let origin = match (arrow, options.align) {
+        (Some(arrow), _) => arrow + arrow_width / 2.0,
+        (None, Align::Start) => 0.0,
+        (None, Align::Center) => length / 2.0,
+        (None, Align::End) => length,
+    };
+    let transform_origin = match side {
+        Side::Top => (origin, height + arrow_height),
+        Side::Right => (-arrow_height, origin),
+        Side::Bottom => (origin, -arrow_height),
+        Side::Left => (width + arrow_height, origin),
+    };
+
+    let available = (space(side) - offset).max(0.0);
+    let (available_width, available_height) = if side.is_block() {
+        (boundary.width, available)
+    } else {
+        (available, boundary.height)
+    };
+
+    Placement {
+        x,
+        y,
+        side,
+        align: options.align,
+        available_width,
+        available_height,
+        anchor_width: anchor.width,
+        anchor_height: anchor.height,
+        arrow,
+        transform_origin,
+    }
+}
+
+/// Context shared with the arrow of floating content
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PopperContext {
+    pub placement: Signal<Option<Placement>>,
+}
+
+fn stop(id: &str, task: &mut Signal<Option<Task>>) {
+    if let Some(task) = task.write().take() {
+        task.cancel();
+    }
+    document::eval(&format!(r#"window.__meekPoppers?.get("{id}")?.()"#));
+}
+
+/// Keep the element with the id `floating` placed next to the `anchor` while `active` is `true`
+///
+/// The elements are measured every animation frame so the placement follows scrolling, resizing and layout changes.
+/// The returned signal is `None` until the elements have been measured.
+pub(crate) fn use_popper(
+    anchor: Memo<PopperAnchor>,
+    floating: Memo<String>,
+    active: Memo<bool>,
+    options: Memo<PopperOptions>,
+) -> Signal<Option<Placement>> {
+    let mut placement = use_signal(|| None::<Placement>);
+    let mut measurements = use_signal(|| None::<Measurements>);
+    let mut task = use_signal(|| None::<Task>);
+
+    let boundary = use_memo(move || options.read().collision_boundary.clone());
+    use_effect(move || {
+        let floating = floating();
+        let anchor = anchor.read().rect_script();
+        let boundary = boundary().unwrap_or_default();
+
+        if task.peek().is_some() {
+            stop(&floating, &mut task);
+        }
+        if !active() {
+            placement.set(None);
+            measurements.set(None);
+            return;
+        }
+
+        let mut eval = document::eval(&format!(r#"
+            window.__meekPoppers ??= new Map();
+
+            let last = "";
+            let frame;
+            const rect = (r) => [r.x, r.y, r.width, r.height];
+            const update = () => {{
+                frame = requestAnimationFrame(update);
+
+                const anchor = {anchor};
+                const floating = document.getElementById("{floating}");
+                if (!anchor || !floating) return;
+
+                const boundary = document.getElementById("{boundary}");
+                const arrow = floating.querySelector("[data-meek-popper-arrow]");
+                // `offset*` sizes ignore transforms, so scaling animations do not affect the placement
+                const values = [
+                    ...rect(anchor),
+                    floating.offsetWidth, floating.offsetHeight,
+                    arrow?.offsetWidth ?? 0, arrow?.offsetHeight ?? 0,
+                    ...(boundary
+                        ? rect(boundary.getBoundingClientRect())
+                        : [0, 0, document.documentElement.clientWidth, document.documentElement.clientHeight]),
+                ];
+
+                const key = values.join(",");
+                if (key !== last) {{
+                    last = key;
+                    dioxus.send(values);
+                }}
+            }};
+            update();
+
+            window.__meekPoppers.set("{floating}", () => {{
+                cancelAnimationFrame(frame);
+                window.__meekPoppers.delete("{floating}");
+            }});
+        "#));
+
+        task.set(Some(spawn(async move {
+            while let Ok(values) = eval.recv::<Vec<f64>>().await {
+                if let Some(values) = Measurements::from_slice(&values) {
+                    measurements.set(Some(values));
+                }
+            }
+        })));
+    });
+
+    // Recompute without measuring again when only the options change
+    use_effect(move || {
+        let computed = measurements().map(|measurements| compute_placement(&measurements, &options.read()));
+        if *placement.peek() != computed {
+            placement.set(computed);
+        }
+    });
+
+    use_drop(move || {
+        if task.peek().is_some() {
+            stop(&floating.peek(), &mut task);
+        }
+    });
+
+    placement
+}
+
+/// Render the arrow of floating content
+///
+/// Must be called from a component rendered inside of content that provides a `PopperContext`
+pub(crate) fn render_arrow(width: f64, height: f64, mut attrs: Vec<Attribute>, children: Element) -> Element {
+    let context = use_context::<PopperContext>();
+    let placement = *context.placement.read();
+
+    let side = placement.map(|p| p.side).unwrap_or_default();
+    if let Some(offset) = placement.and_then(|p| p.arrow) {
+        let edge = if side.is_block() { "left" } else { "top" };
+        attrs.push(Attribute::new(edge, format!("{offset}px"), Some("style"), false));
+    }
+    // The arrow sits against the side of the content that faces the anchor
+    attrs.push(Attribute::new(side.opposite().as_str(), "0px", Some("style"), false));
+
+    let (transform, origin) = match side {
+        Side::Top => ("translateY(100%)", "center"),
+        Side::Right => ("translateY(50%) rotate(90deg) translateX(-50%)", "0 0"),
+        Side::Bottom => ("rotate(180deg)", "center 0"),
+        Side::Left => ("translateY(50%) rotate(-90deg) translateX(50%)", "100% 0"),
+    };
+
+    rsx! {
+        span {
+            "data-meek-popper-arrow": true,
+            position: "absolute",
+            display: "block",
+            line_height: "0",
+            transform: transform,
+            transform_origin: origin,
+            visibility: if placement.is_none() { "hidden" },
+
+            ..attrs,
+
+            if children != VNode::empty() {
+                {children}
+            } else {
+                svg {
+                    width: width,
+                    height: height,
+                    view_box: "0 0 30 10",
+                    preserve_aspect_ratio: "none",
+                    display: "block",
+                    polygon { points: "0,0 30,0 15,10", fill: "currentColor" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Options that place `100x50` content `5px` away from the anchor, avoiding collisions
+    fn options(side: Side) -> PopperOptions {
+        PopperOptions {
+            side,
+            side_offset: 5.0,
+            avoid_collisions: true,
+            ..Default::default()
+        }
+    }
+
+    /// Measurements of `100x50` content next to an anchor inside of a `500x500` boundary at the origin
+    fn measurements(x: f64, y: f64, width: f64, height: f64) -> Measurements {
+        Measurements {
+            anchor: Rect { x, y, width, height },
+            floating: (100.0, 50.0),
+            arrow: None,
+            boundary: Rect { x: 0.0, y: 0.0, width: 500.0, height: 500.0 },
+        }
+    }
+
+    #[test]
+    fn places_content_on_the_requested_side() {
+        let placement = compute_placement(&measurements(200.0, 200.0, 100.0, 20.0), &options(Side::Bottom));
+        assert_eq!((placement.side, placement.x, placement.y), (Side::Bottom, 200.0, 225.0));
+        assert_eq!(placement.available_height, 275.0);
+    }
+
+    #[test]
+    fn flips_to_the_opposite_side_against_the_boundary() {
+        let placement = compute_placement(&measurements(200.0, 460.0, 100.0, 20.0), &options(Side::Bottom));
+        assert_eq!((placement.side, placement.y), (Side::Top, 405.0));
+
+        let placement = compute_placement(&measurements(460.0, 200.0, 30.0, 20.0), &options(Side::Right));
+        assert_eq!((placement.side, placement.x), (Side::Left, 355.0));
+    }
+
+    #[test]
+    fn keeps_the_side_when_the_opposite_side_has_less_room() {
+        let placement = compute_placement(&measurements(200.0, 10.0, 100.0, 480.0), &options(Side::Bottom));
+        assert_eq!((placement.side, placement.y), (Side::Bottom, 495.0));
+    }
+
+    #[test]
+    fn shifts_along_the_side_to_stay_inside_of_the_boundary() {
+        let placement = compute_placement(&measurements(460.0, 200.0, 30.0, 20.0), &options(Side::Bottom));
+        assert_eq!(placement.x, 400.0);
+
+        let placement = compute_placement(&measurements(0.0, 200.0, 30.0, 20.0), &options(Side::Bottom));
+        assert_eq!(placement.x, 0.0);
+    }
+
+    #[test]
+    fn collision_padding_shrinks_the_boundary() {
+        let options = PopperOptions { collision_padding: 10.0, ..options(Side::Bottom) };
+        let placement = compute_placement(&measurements(460.0, 200.0, 30.0, 20.0), &options);
+        assert_eq!(placement.x, 390.0);
+
+        let placement = compute_placement(&measurements(200.0, 440.0, 100.0, 20.0), &options);
+        assert_eq!(placement.side, Side::Top);
+    }
+
+    #[test]
+    fn ignores_the_boundary_without_avoiding_collisions() {
+        let options = PopperOptions { avoid_collisions: false, ..options(Side::Bottom) };
+        let placement = compute_placement(&measurements(460.0, 460.0, 30.0, 20.0), &options);
+        assert_eq!((placement.side, placement.x, placement.y), (Side::Bottom, 425.0, 485.0));
+    }
+
+    #[test]
+    fn points_the_arrow_at_the_anchor_after_shifting() {
+        let measurements = Measurements { arrow: Some((10.0, 5.0)), ..measurements(460.0, 200.0, 30.0, 20.0) };
+        let placement = compute_placement(&measurements, &options(Side::Bottom));
+        assert_eq!((placement.x, placement.y), (400.0, 230.0));
+        assert_eq!(placement.arrow, Some(70.0));
+    }
+}
@@ -14,11 +14,7 @@ impl Portals {
     fn insert(&mut self, name: Option<String>, id: &String, children: Element) {
         let name = name.unwrap_or_default();
         if !self.entries.contains_key(id) {
-            if self.mappings.contains_key(&name) {
-                self.mappings.get_mut(&name).unwrap().push(id.clone());
-            } else {
-                self.mappings.insert(name, Vec::from([id.clone()]));
-            }
+            self.mappings.entry(name).or_default().push(id.clone());
         }
 
         self.entries.insert(id.clone(), children);
@@ -39,7 +35,7 @@ impl Portals {
 
     fn entries(&self, name: Option<String>) -> Vec<&Element> {
         let name = name.unwrap_or_default();
-        let ids = self.mappings.get(&name).map(|v| v.into_iter().collect::<Vec<&String>>()).unwrap_or_default();
+        let ids = self.mappings.get(&name).map(|v| v.iter().collect::<Vec<&String>>()).unwrap_or_default();
         ids.into_iter()
             .filter_map(|id| self.entries.get(id))
             .collect::<Vec<_>>()
@@ -62,12 +58,12 @@ pub fn Teleport(
     name: Option<String>,
     children: Element
 ) -> Element {
-    let id = use_signal(|| create_id());
+    let id = use_signal(create_id);
 
     // WARN: Child components will no longer inherit the scope and context of the parent
     // TODO: Pass scope/context down so it can be used
     use_effect(use_reactive((&name, &children), move |(name, children)| {
-        PORTALS.write().insert(name, &*id.read(), rsx! { {children} });
+        PORTALS.write().insert(name, &id.read(), rsx! { {children} });
     }));
 
     use_drop(move || {
@@ -0,0 +1,125 @@
+use std::rc::Rc;
+
+use dioxus::{html::geometry::PixelsSize, prelude::*};
+
+use crate::create_id;
+
+/// Lifecycle of an element that is animated in and out
+#[derive(Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+enum PresenceState {
+    Mounted,
+    /// Waiting for the exit animations to finish
+    UnmountSuspended,
+    Unmounted,
+}
+
+/// Track whether the element with the given `id` should be rendered
+///
+/// The returned memo stays `true` after `present` becomes `false` until all of the element's
+/// running animations and transitions have finished. Infinite animations are ignored.
+pub(crate) fn use_presence(id: Memo<String>, present: Memo<bool>) -> Memo<bool> {
+    let mut state = use_signal(|| if *present.peek() { PresenceState::Mounted } else { PresenceState::Unmounted });
+    let mut generation = use_signal(|| 0usize);
+
+    use_effect(move || {
+        let present = present();
+        *generation.write() += 1;
+
+        if present {
+            state.set(PresenceState::Mounted);
+            return;
+        }
+
+        if state.peek().is_unmounted() {
+            return;
+        }
+
+        state.set(PresenceState::UnmountSuspended);
+
+        let current = *generation.peek();
+        let id = id.peek().clone();
+        spawn(async move {
+            // Effects run after the DOM is updated so the exit animations have already been started
+            let mut eval = document::eval(&format!(r#"
+                const element = document.getElementById("{id}");
+                const animations = element?.getAnimations({{ subtree: true }})
+                    .filter((animation) => animation.effect?.getComputedTiming().endTime !== Infinity) ?? [];
+                await Promise.allSettled(animations.map((animation) => animation.finished));
+                dioxus.send(true);
+            "#));
+            let _ = eval.recv::<bool>().await;
+
+            if *generation.peek() == current && state.peek().is_unmount_suspended() {
+                state.set(PresenceState::Unmounted);
+            }
+        });
+    });
+
+    use_memo(move || !state.read().is_unmounted())
+}
+
+/// Measure the content of a disclosure each time it opens so that its size can be animated
+///
+/// Set the returned signal from the `onmounted` handler of the content. The measured size is added to `attrs` as the
+/// width and height CSS variables named in `variables`.
+pub(crate) fn use_content_size(
+    variables: (&'static str, &'static str),
+    open: Memo<bool>,
+    is_present: Memo<bool>,
+    attrs: &mut Vec<Attribute>,
+) -> Signal<Option<Rc<MountedData>>> {
+    let content = use_signal(|| None::<Rc<MountedData>>);
+    let mut size = use_signal(|| None::<PixelsSize>);
+    use_effect(move || {
+        if !(open() && is_present()) {
+            return;
+        }
+
+        if let Some(data) = content.read().clone() {
+            spawn(async move {
+                if let Ok(measured) = data.get_scroll_size().await {
+                    size.set(Some(measured));
+                }
+            });
+        }
+    });
+
+    if let Some(size) = size() {
+        let (width, height) = variables;
+        attrs.push(Attribute::new(width, format!("{}px", size.width), Some("style"), false));
+        attrs.push(Attribute::new(height, format!("{}px", size.height), Some("style"), false));
+    }
+
+    content
+}
+
+/// Keeps its children mounted until their exit animations have finished
+///
+/// Children are wrapped in a `div` with `display: contents` so that the animations of the
+/// rendered elements can be observed without affecting the layout.
+#[component]
+pub fn Presence(
+    /// Whether the children should be present
+    present: bool,
+    /// Keep the children mounted even when they are not present
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    children: Element,
+) -> Element {
+    let id = use_memo(create_id);
+    let present = use_memo(use_reactive!(|present, force_mount| present || force_mount.unwrap_or_default()));
+    let is_present = use_presence(id, present);
+
+    rsx! {
+        if is_present() {
+            div {
+                id: id,
+                display: "contents",
+
+                {children}
+            }
+        }
+    }
+}
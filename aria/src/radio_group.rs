@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::{create_id, dom_order, presence::use_presence, roving_focus::RovingFocus, Orientation, OptionalMaybeSignal};
+
+/// Selector matching the items of a radio group
+const ITEMS: &str = "[data-meek-radio-group-item]";
+
+/// Handles the contextual state of a radio group
+#[derive(Debug)]
+struct RadioGroupState {
+    id: String,
+    pub name: Option<String>,
+    pub required: bool,
+    pub disabled: bool,
+    pub orientation: Option<Orientation>,
+
+    focus: RovingFocus,
+    /// Values of the items by the id of their element
+    ids: HashMap<String, String>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+
+    current: Signal<String>,
+    onchange: Option<EventHandler<String>>,
+}
+
+impl RadioGroupState {
+    /// Check if an item is the checked item
+    pub fn is_checked(&self, value: impl AsRef<str>) -> bool {
+        *self.current.read() == value.as_ref()
+    }
+
+    /// Whether the item is the one that is reached when tabbing into the group
+    fn is_tab_stop(&self, value: &str) -> bool {
+        let current = self.current.read();
+        if self.focus.keys().any(|key| key == current.as_str()) {
+            *current == value
+        } else {
+            self.focus.first() == Some(value)
+        }
+    }
+
+    /// Check an item
+    pub fn select(&mut self, value: impl AsRef<str>) {
+        let value = value.as_ref();
+        if !self.is_checked(value) {
+            self.current.set(value.to_string());
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(value.to_string());
+            }
+        }
+    }
+}
+
+/// Radio Group
+///
+/// A set of checkable buttons, known as radio buttons, where no more than one of the buttons can be checked at a time.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn RadioGroup(
+    /// The checked item
+    ///
+    /// When a signal is passed it is kept in sync with the checked item
+    #[props(into, default)]
+    value: OptionalMaybeSignal<String>,
+    /// The item that is checked by default
+    #[props(into)]
+    default: Option<String>,
+    /// Handler that is run when the checked item changes
+    onchange: Option<EventHandler<String>>,
+
+    /// Name of the group when submitted as part of a form
+    #[props(into)]
+    name: Option<String>,
+    /// Whether an item must be checked before the owning form can be submitted
+    required: Option<bool>,
+    /// Whether every item in the group is disabled
+    disabled: Option<bool>,
+    /// Direction of the group. When not set both horizontal and vertical arrow keys move between items
+    orientation: Option<Orientation>,
+    /// Whether keyboard navigation wraps around from the last item to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+    /// User defined ID of the radio group
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes that are to be added to the group `div` container
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(default.unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(RadioGroupState {
+        id: id.unwrap_or(create_id()),
+        name: name.clone(),
+        required: required.unwrap_or_default(),
+        disabled: disabled.unwrap_or_default(),
+        orientation,
+        focus: RovingFocus::new(orientation, r#loop.unwrap_or(true)),
+        ids: HashMap::new(),
+        ordering: false,
+        current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            let mut current = state.peek().current;
+            if *current.peek() != value {
+                current.set(value);
+            }
+        }
+    }));
+
+    use_effect(use_reactive!(|name, required, disabled, orientation, r#loop| {
+        let mut state = state.write();
+        state.name = name;
+        state.required = required.unwrap_or_default();
+        state.disabled = disabled.unwrap_or_default();
+        state.orientation = orientation;
+        state.focus.orientation = orientation;
+        state.focus.looped = r#loop.unwrap_or(true);
+    }));
+
+    rsx! {
+        div {
+            role: "radiogroup",
+            id: state.read().id.as_str(),
+            aria_required: state.read().required,
+            aria_orientation: state.read().orientation,
+            "data-orientation": state.read().orientation,
+            "data-disabled": state.read().disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<RadioGroupState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.id.clone()
+        };
+        let order = dom_order(&id, ITEMS).await;
+        let order: Vec<String> = {
+            let state = state.peek();
+            order.iter().filter_map(|id| state.ids.get(id).cloned()).collect()
+        };
+        if !state.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            state.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Handles the contextual state of a radio group item
+#[derive(Debug, Clone, PartialEq)]
+struct RadioGroupItemState {
+    value: String,
+    disabled: bool,
+}
+
+/// An item in the group that can be checked
+///
+/// A hidden native `input` is rendered next to the item so that the group's value is submitted with a form.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the checked item in the group, or the first item if none are checked.
+/// - `<Space>`: Checks the focused item if it is not already checked.
+/// - `<ArrowDown>`: Moves focus to and checks the next item in the group.
+/// - `<ArrowRight>`: Moves focus to and checks the next item in the group.
+/// - `<ArrowUp>`: Moves focus to and checks the previous item in the group.
+/// - `<ArrowLeft>`: Moves focus to and checks the previous item in the group.
+#[component]
+pub fn RadioGroupItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` event is run and what is submitted with a form
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the item; `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<RadioGroupState>>();
+    let id = use_hook(|| id.unwrap_or(create_id()));
+    let disabled = state.read().disabled || disabled.unwrap_or_default();
+
+    let mut item = use_context_provider(|| Signal::new(RadioGroupItemState { value: value.clone(), disabled }));
+    let value = use_signal(|| value);
+
+    use_effect(use_reactive!(|disabled| {
+        item.write().disabled = disabled;
+        state.write().focus.set_disabled(value.peek().as_str(), disabled);
+    }));
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            state.write().ids.insert(id.clone(), value.peek().clone());
+        }
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.focus.remove_item(value.peek().as_str());
+                state.ids.remove(&id);
+            }
+        }
+    });
+
+    let checked = state.read().is_checked(value.read().as_str());
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "radio",
+            id: id.as_str(),
+            aria_checked: checked,
+            value: value.read().as_str(),
+            tabindex: if state.read().is_tab_stop(&value.read()) { 0 } else { -1 },
+            disabled: disabled,
+
+            "data-meek-radio-group-item": true,
+            "data-state": if checked { "checked" } else { "unchecked" },
+            "data-disabled": disabled,
+
+            onclick: move |_| if !disabled { state.write().select(value.read().as_str()) },
+            onkeydown: move |evt| async move {
+                // According to WAI ARIA, radio groups don't activate items on enter keypress
+                if evt.key() == Key::Enter {
+                    evt.prevent_default();
+                    return;
+                }
+
+                let target = state.read().focus.navigate(value.read().as_str(), &evt);
+                if let Some((target, data)) = target {
+                    state.write().select(target);
+                    // TODO: Handle set_focus result response
+                    let _ = data.set_focus(true).await;
+                }
+            },
+
+            onmounted: move |v: Event<MountedData>| {
+                state.write().focus.add_item(value.read().as_str(), v.data());
+                schedule_order(state);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+        input {
+            r#type: "radio",
+            aria_hidden: true,
+            tabindex: -1,
+            value: value.read().as_str(),
+            checked: checked,
+            name: state.read().name.clone(),
+            required: state.read().required,
+            disabled,
+
+            transform: "translateX(-100%)",
+            position: "absolute",
+            pointer_events: "none",
+            opacity: 0,
+            margin: "0px",
+            width: "0px",
+            height: "0px",
+        }
+    }
+}
+
+/// Renders when the radio item is in a checked state
+///
+/// The indicator stays mounted until its exit animations have finished after the item is unchecked.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn RadioGroupIndicator(
+    /// Keep the indicator mounted even when the item is unchecked
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the indicator `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<RadioGroupState>>();
+    let item = use_context::<Signal<RadioGroupItemState>>();
+
+    let id = use_memo(create_id);
+    let checked = use_memo(move || state.read().is_checked(item.read().value.as_str()));
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || checked()));
+    let is_present = use_presence(id, present);
+
+    rsx! {
+        if is_present() {
+            span {
+                id: id,
+                "data-state": if checked() { "checked" } else { "unchecked" },
+                "data-disabled": item.read().disabled,
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
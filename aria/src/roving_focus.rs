@@ -0,0 +1,325 @@
+use std::{collections::{BTreeMap, HashSet}, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::Orientation;
+
+/// Keeps track of a group of focusable items where arrow keys move focus between them
+#[derive(Debug)]
+pub(crate) struct RovingFocus {
+    /// Which arrow keys move focus. `None` means both directions are allowed
+    pub orientation: Option<Orientation>,
+    /// Whether focus wraps around when moving past the first or last item
+    pub looped: bool,
+
+    items: Vec<(String, Rc<MountedData>)>,
+    item_map: BTreeMap<String, usize>,
+    disabled: HashSet<String>,
+}
+
+impl RovingFocus {
+    pub fn new(orientation: Option<Orientation>, looped: bool) -> Self {
+        Self {
+            orientation,
+            looped,
+            items: Default::default(),
+            item_map: Default::default(),
+            disabled: Default::default(),
+        }
+    }
+
+    /// Register an item with the group
+    pub fn add_item(&mut self, key: impl AsRef<str>, data: Rc<MountedData>) {
+        let key = key.as_ref();
+        if !self.item_map.contains_key(key) {
+            self.item_map.insert(key.to_string(), self.items.len());
+            self.items.push((key.to_string(), data));
+        }
+    }
+
+    /// Remove an item from the group
+    pub fn remove_item(&mut self, key: impl AsRef<str>) {
+        if let Some(index) = self.item_map.remove(key.as_ref()) {
+            self.items.remove(index);
+            for position in self.item_map.values_mut() {
+                if *position > index {
+                    *position -= 1;
+                }
+            }
+        }
+    }
+
+    /// Change the key of an item, keeping its position
+    pub fn rename_item(&mut self, from: impl AsRef<str>, to: impl AsRef<str>) {
+        let (from, to) = (from.as_ref(), to.as_ref());
+        if let Some(index) = self.item_map.remove(from) {
+            self.items[index].0 = to.to_string();
+            self.item_map.insert(to.to_string(), index);
+        }
+        if self.disabled.remove(from) {
+            self.disabled.insert(to.to_string());
+        }
+    }
+
+    /// Sort the items in the order of `keys`, usually the order they are in the document
+    ///
+    /// Items that aren't in `keys` keep their relative order after the others.
+    pub fn reorder(&mut self, keys: &[String]) {
+        let position = |key: &String| keys.iter().position(|other| other == key).unwrap_or(usize::MAX);
+        self.items.sort_by_cached_key(|(key, _)| position(key));
+        self.item_map = self.items.iter().enumerate().map(|(index, (key, _))| (key.clone(), index)).collect();
+    }
+
+    /// Mark whether an item can receive focus with the arrow keys
+    pub fn set_disabled(&mut self, key: impl AsRef<str>, disabled: bool) {
+        let key = key.as_ref();
+        if disabled {
+            self.disabled.insert(key.to_string());
+        } else {
+            self.disabled.remove(key);
+        }
+    }
+
+    /// Keys of the registered items in the order they were added
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Position of the item in the order the items were added
+    pub fn position(&self, key: impl AsRef<str>) -> Option<usize> {
+        self.item_map.get(key.as_ref()).copied()
+    }
+
+    /// Keys of the enabled items from `from` to `to`, both included, in the order they were added
+    pub fn range(&self, from: impl AsRef<str>, to: impl AsRef<str>) -> Vec<String> {
+        let (Some(from), Some(to)) = (self.position(from), self.position(to)) else {
+            return Vec::new();
+        };
+        let (start, end) = if from <= to { (from, to) } else { (to, from) };
+        self.items[start..=end].iter()
+            .filter(|(key, _)| !self.disabled.contains(key))
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Key of the first item that can be focused
+    pub fn first(&self) -> Option<&str> {
+        self.keys().find(|key| !self.disabled.contains(*key))
+    }
+
+    /// Key of the last item that can be focused
+    pub fn last(&self) -> Option<&str> {
+        self.edge(true).map(|index| self.items[index].0.as_str())
+    }
+
+    /// Mounted data of the item with the given key
+    pub fn get(&self, key: impl AsRef<str>) -> Option<Rc<MountedData>> {
+        let index = *self.item_map.get(key.as_ref())?;
+        Some(self.items[index].1.clone())
+    }
+
+    /// Find the next enabled item in the given direction, starting from `index`
+    fn step(&self, index: usize, forward: bool) -> Option<usize> {
+        let len = self.items.len();
+        let mut current = index;
+        for _ in 0..len {
+            current = match (forward, current) {
+                (true, c) if c + 1 < len => c + 1,
+                (true, _) if self.looped => 0,
+                (false, 0) if self.looped => len - 1,
+                (false, c) if c > 0 => c - 1,
+                _ => return None,
+            };
+
+            if current == index {
+                return None;
+            }
+            if !self.disabled.contains(&self.items[current].0) {
+                return Some(current);
+            }
+        }
+        None
+    }
+
+    /// Find the first or last enabled item
+    fn edge(&self, last: bool) -> Option<usize> {
+        let enabled = |index: &usize| !self.disabled.contains(&self.items[*index].0);
+        if last {
+            (0..self.items.len()).rev().find(enabled)
+        } else {
+            (0..self.items.len()).find(enabled)
+        }
+    }
+
+    /// Resolve which item should receive focus for the key pressed while the item `key` has focus
+    ///
+    /// Prevents the default behavior of handled keys. Returns the key and mounted data of the item to focus.
+    pub fn navigate(&self, key: impl AsRef<str>, evt: &Event<KeyboardData>) -> Option<(String, Rc<MountedData>)> {
+        let index = *self.item_map.get(key.as_ref())?;
+
+        let vertical = self.orientation.is_none_or(|o| o.is_vertical());
+        let horizontal = self.orientation.is_none_or(|o| o.is_horizontal());
+
+        let target = match evt.key() {
+            Key::ArrowUp if vertical => self.step(index, false),
+            Key::ArrowDown if vertical => self.step(index, true),
+            Key::ArrowLeft if horizontal => self.step(index, false),
+            Key::ArrowRight if horizontal => self.step(index, true),
+            Key::Home => self.edge(false),
+            Key::End => self.edge(true),
+            _ => return None,
+        };
+
+        evt.prevent_default();
+        target
+            .filter(|target| *target != index)
+            .map(|target| self.items[target].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Keyboard data of a key pressed without modifiers
+    struct Pressed(Key);
+
+    impl ModifiersInteraction for Pressed {
+        fn modifiers(&self) -> Modifiers {
+            Modifiers::empty()
+        }
+    }
+
+    impl HasKeyboardData for Pressed {
+        fn key(&self) -> Key {
+            self.0.clone()
+        }
+
+        fn code(&self) -> Code {
+            Code::Unidentified
+        }
+
+        fn location(&self) -> Location {
+            Location::Standard
+        }
+
+        fn is_auto_repeating(&self) -> bool {
+            false
+        }
+
+        fn is_composing(&self) -> bool {
+            false
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    /// A group with the items `a`, `b`, `c`... and the given items disabled
+    fn group(len: usize, disabled: &[&str], looped: bool) -> RovingFocus {
+        let mut focus = RovingFocus::new(Some(Orientation::Vertical), looped);
+        for key in ["a", "b", "c", "d"].into_iter().take(len) {
+            focus.add_item(key, Rc::new(MountedData::new(())));
+        }
+        for key in disabled {
+            focus.set_disabled(key, true);
+        }
+        focus
+    }
+
+    /// Key of the item that receives focus when `key` is pressed on the item `from`
+    fn navigate(focus: &RovingFocus, from: &str, key: Key) -> Option<String> {
+        let evt = Event::new(Rc::new(KeyboardData::new(Pressed(key))), true);
+        focus.navigate(from, &evt).map(|(key, _)| key)
+    }
+
+    #[test]
+    fn step_skips_disabled_items() {
+        let focus = group(4, &["b", "c"], false);
+        assert_eq!(focus.step(0, true), Some(3));
+        assert_eq!(focus.step(3, false), Some(0));
+    }
+
+    #[test]
+    fn step_stops_at_edges_without_looping() {
+        let focus = group(3, &[], false);
+        assert_eq!(focus.step(2, true), None);
+        assert_eq!(focus.step(0, false), None);
+    }
+
+    #[test]
+    fn step_wraps_around_when_looping() {
+        let focus = group(3, &["a"], true);
+        assert_eq!(focus.step(2, true), Some(1));
+        assert_eq!(focus.step(1, false), Some(2));
+    }
+
+    #[test]
+    fn step_finds_nothing_when_all_items_are_disabled() {
+        let focus = group(3, &["a", "b", "c"], true);
+        assert_eq!(focus.step(0, true), None);
+        assert_eq!(focus.step(0, false), None);
+        assert_eq!(focus.first(), None);
+        assert_eq!(focus.last(), None);
+    }
+
+    #[test]
+    fn step_stays_on_a_single_item() {
+        let focus = group(1, &[], true);
+        assert_eq!(focus.step(0, true), None);
+        assert_eq!(focus.step(0, false), None);
+    }
+
+    #[test]
+    fn range_includes_both_ends_and_skips_disabled_items() {
+        let focus = group(4, &["b"], false);
+        assert_eq!(focus.range("a", "c"), vec!["a", "c"]);
+        assert_eq!(focus.range("d", "b"), vec!["c", "d"]);
+        assert_eq!(focus.range("a", "e"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn range_is_empty_when_all_items_are_disabled() {
+        let focus = group(3, &["a", "b", "c"], false);
+        assert!(focus.range("a", "c").is_empty());
+    }
+
+    #[test]
+    fn navigate_follows_orientation() {
+        let focus = group(3, &[], false);
+        assert_eq!(navigate(&focus, "a", Key::ArrowDown), Some("b".to_string()));
+        assert_eq!(navigate(&focus, "b", Key::ArrowUp), Some("a".to_string()));
+        assert_eq!(navigate(&focus, "a", Key::ArrowRight), None);
+    }
+
+    #[test]
+    fn navigate_moves_to_enabled_edges() {
+        let focus = group(4, &["a", "d"], false);
+        assert_eq!(navigate(&focus, "c", Key::Home), Some("b".to_string()));
+        assert_eq!(navigate(&focus, "b", Key::End), Some("c".to_string()));
+        assert_eq!(navigate(&focus, "b", Key::Home), None);
+    }
+
+    #[test]
+    fn navigate_loops_past_the_last_item() {
+        let focus = group(3, &[], true);
+        assert_eq!(navigate(&focus, "c", Key::ArrowDown), Some("a".to_string()));
+        assert_eq!(navigate(&focus, "a", Key::ArrowUp), Some("c".to_string()));
+    }
+
+    #[test]
+    fn navigate_ignores_single_and_disabled_groups() {
+        assert_eq!(navigate(&group(1, &[], true), "a", Key::ArrowDown), None);
+        assert_eq!(navigate(&group(1, &[], true), "a", Key::End), None);
+        let disabled = group(3, &["a", "b", "c"], true);
+        assert_eq!(navigate(&disabled, "a", Key::ArrowDown), None);
+        assert_eq!(navigate(&disabled, "a", Key::End), None);
+    }
+
+    #[test]
+    fn navigate_ignores_unknown_items() {
+        let focus = group(2, &[], true);
+        assert_eq!(navigate(&focus, "z", Key::ArrowDown), None);
+    }
+}
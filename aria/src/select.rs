@@ -0,0 +1,1241 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    dom_order,
+    focus_scope::use_focus_scope,
+    popper::{use_popper, Placement, PopperAnchor, PopperContext, PopperOptions},
+    presence::use_presence,
+    roving_focus::RovingFocus,
+    sleep,
+    typeahead::typeahead,
+    Align,
+    DismissableLayerEvent,
+    FocusScopeEvent,
+    OptionalMaybeSignal,
+    Orientation,
+    Provider,
+    Side,
+    Teleport,
+};
+
+/// Selector matching the items of a select
+const ITEMS: &str = "[data-meek-select-item]";
+/// Selector matching the items of a select that can be chosen
+const ITEM_SELECTOR: &str = "[data-meek-select-item]:not([data-disabled])";
+
+/// Distance in pixels kept between item aligned content and the edges of the viewport
+const CONTENT_MARGIN: f64 = 10.0;
+
+/// How often the viewport is scrolled while the pointer is over a scroll button
+const SCROLL_INTERVAL: u64 = 50;
+
+/// How the content of a select is positioned
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum SelectPosition {
+    /// The content covers the trigger with the selected item placed over the value, like a native `select`
+    #[default]
+    ItemAligned,
+    /// The content is placed next to the trigger, like a popover
+    Popper,
+}
+
+impl<A: AsRef<str>> From<A> for SelectPosition {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "item-aligned" => Self::ItemAligned,
+            "popper" => Self::Popper,
+            other => panic!("unknown select position: '{other}'")
+        }
+    }
+}
+
+/// An item that is rendered in the content, used for the displayed value and the native `select`
+#[derive(Debug, Clone, PartialEq)]
+struct SelectOption {
+    /// Id of the item element
+    key: String,
+    value: String,
+    text: String,
+    disabled: bool,
+}
+
+/// Handles the contextual state of a select
+#[derive(Debug)]
+struct SelectState {
+    id: String,
+    pub name: Option<String>,
+    pub required: bool,
+    pub disabled: bool,
+
+    options: Vec<SelectOption>,
+
+    current: Signal<String>,
+    onchange: Option<EventHandler<String>>,
+
+    open: Signal<bool>,
+    onopenchange: Option<EventHandler<bool>>,
+}
+
+impl SelectState {
+    /// Get the formatted trigger id
+    fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    /// Get the formatted id of the element that displays the value
+    fn value_id(&self) -> String {
+        format!("{}:value", self.id)
+    }
+
+    /// Get the formatted id of the element that positions the content
+    fn popper_id(&self) -> String {
+        format!("{}:popper", self.id)
+    }
+
+    /// Get the formatted id of the scrollable element in the content
+    fn viewport_id(&self) -> String {
+        format!("{}:viewport", self.id)
+    }
+
+    /// Check if an item is the selected item
+    fn is_selected(&self, value: impl AsRef<str>) -> bool {
+        *self.current.read() == value.as_ref()
+    }
+
+    /// The option of the selected item
+    fn selected(&self) -> Option<&SelectOption> {
+        let current = self.current.read();
+        self.options.iter().find(|option| option.value == *current)
+    }
+
+    fn add_option(&mut self, option: SelectOption) {
+        match self.options.iter_mut().find(|existing| existing.key == option.key) {
+            Some(existing) => *existing = option,
+            None => self.options.push(option),
+        }
+    }
+
+    fn remove_option(&mut self, key: &str) {
+        self.options.retain(|option| option.key != key);
+    }
+
+    /// Select an item
+    fn select(&mut self, value: impl AsRef<str>) {
+        let value = value.as_ref();
+        if !self.is_selected(value) {
+            self.current.set(value.to_string());
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(value.to_string());
+            }
+        }
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if *self.open.peek() != open {
+            self.open.set(open);
+            if let Some(onopenchange) = self.onopenchange.as_ref() {
+                onopenchange.call(open);
+            }
+        }
+    }
+}
+
+/// Select
+///
+/// Displays a list of options for the user to pick from, triggered by a button.
+///
+/// A hidden native `select` is rendered so that the value is submitted with a form.
+#[component]
+pub fn Select(
+    /// The selected item
+    ///
+    /// When a signal is passed it is kept in sync with the selected item
+    #[props(into, default)]
+    value: OptionalMaybeSignal<String>,
+    /// The item that is selected by default
+    #[props(into)]
+    default: Option<String>,
+    /// Handler that is run when the selected item changes
+    onchange: Option<EventHandler<String>>,
+
+    /// Passed state whether the select should be open or closed
+    open: Option<bool>,
+    /// Default open state of the select
+    default_open: Option<bool>,
+    /// Event handler for when the open state changes
+    onopenchange: Option<EventHandler<bool>>,
+
+    /// Name of the select when submitted as part of a form
+    #[props(into)]
+    name: Option<String>,
+    /// Whether an item must be selected before the owning form can be submitted
+    required: Option<bool>,
+    /// Whether the select is disabled
+    disabled: Option<bool>,
+    /// User defined ID of the select content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(default.unwrap_or_default());
+    let mut is_open = use_signal(|| open.or(default_open).unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(SelectState {
+        id: id.unwrap_or(create_id()),
+        name: name.clone(),
+        required: required.unwrap_or_default(),
+        disabled: disabled.unwrap_or_default(),
+        options: Vec::new(),
+        current,
+        onchange,
+        open: is_open,
+        onopenchange,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            let mut current = state.peek().current;
+            if *current.peek() != value {
+                current.set(value);
+            }
+        }
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            is_open.set(open);
+        }
+    }));
+
+    use_effect(use_reactive!(|name, required, disabled| {
+        let mut state = state.write();
+        state.name = name;
+        state.required = required.unwrap_or_default();
+        state.disabled = disabled.unwrap_or_default();
+    }));
+
+    let select = state.read();
+    let current = select.current.read().clone();
+
+    rsx! {
+        {children}
+        select {
+            aria_hidden: true,
+            tabindex: -1,
+            name: select.name.clone(),
+            required: select.required,
+            disabled: select.disabled,
+
+            // Keeps the state in sync when the browser autofills the form
+            onchange: move |evt| state.write().select(evt.value()),
+
+            transform: "translateX(-100%)",
+            position: "absolute",
+            pointer_events: "none",
+            opacity: 0,
+            margin: "0px",
+            width: "0px",
+            height: "0px",
+
+            option { value: "", selected: current.is_empty() }
+            for item in select.options.iter() {
+                option {
+                    key: "{item.key}",
+                    value: item.value.as_str(),
+                    selected: item.value == current,
+                    disabled: item.disabled,
+                    {item.text.clone()}
+                }
+            }
+        }
+    }
+}
+
+/// The button that toggles the select
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-disabled]`: Present when disabled
+/// - `[data-placeholder]`: Present when no item is selected
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Opens the select and focuses the selected item.
+/// - `<Enter>`: Opens the select and focuses the selected item.
+/// - `<ArrowDown>`: Opens the select and focuses the selected item.
+/// - `<ArrowUp>`: Opens the select and focuses the selected item.
+/// - Typing a character selects the next item whose text starts with the typed characters.
+#[component]
+pub fn SelectTrigger(
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<SelectState>>();
+    // Touch and pen presses open the select on click, so scrolling the page does not open it
+    let mut pointer_type = use_signal(|| String::from("touch"));
+
+    let disabled = state.read().disabled;
+    let open = *state.read().open.read();
+    let placeholder = state.read().current.read().is_empty();
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "combobox",
+            id: state.read().trigger_id(),
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_required: state.read().required,
+            aria_autocomplete: "none",
+            aria_expanded: open,
+            aria_controls: if open { Some(state.read().id.clone()) } else { None },
+
+            "data-state": if open { "open" } else { "closed" },
+            "data-disabled": disabled,
+            "data-placeholder": placeholder,
+
+            onpointerdown: move |evt: Event<PointerData>| {
+                pointer_type.set(evt.pointer_type());
+                // Only the primary button without `<Ctrl>` toggles, which opens the context menu on macOS
+                if disabled
+                    || evt.pointer_type() != "mouse"
+                    || evt.trigger_button() != Some(dioxus::html::input_data::MouseButton::Primary)
+                    || evt.modifiers().ctrl()
+                {
+                    return;
+                }
+                let mut state = state.write();
+                let open = !*state.open.peek();
+                state.set_open(open);
+            },
+            onclick: move |_| {
+                if !disabled && *pointer_type.peek() != "mouse" {
+                    state.write().set_open(true);
+                }
+            },
+            onkeydown: move |evt: Event<KeyboardData>| async move {
+                if disabled {
+                    return;
+                }
+                let modifiers = evt.modifiers();
+                match evt.key() {
+                    Key::Enter | Key::ArrowDown | Key::ArrowUp => {
+                        evt.prevent_default();
+                        state.write().set_open(true);
+                    }
+                    Key::Character(ref character) if character == " " => {
+                        evt.prevent_default();
+                        state.write().set_open(true);
+                    }
+                    Key::Character(ref character) if character.chars().count() == 1
+                        && !modifiers.ctrl()
+                        && !modifiers.alt()
+                        && !modifiers.meta() =>
+                    {
+                        // The items are rendered while the select is closed, so they can be searched
+                        let (id, current) = {
+                            let state = state.read();
+                            (state.id.clone(), state.selected().map(|option| option.key.clone()))
+                        };
+                        if let Some(target) = typeahead(&id, ITEM_SELECTOR, character, current.as_deref()).await {
+                            let value = state.read().options.iter()
+                                .find(|option| option.key == target)
+                                .map(|option| option.value.clone());
+                            if let Some(value) = value {
+                                state.write().select(value);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Displays the text of the selected item. Must live inside `SelectTrigger`
+///
+/// Pass children to display something else than the text of the selected item.
+///
+/// # Data Attributes
+///
+/// - `[data-placeholder]`: Present when no item is selected
+#[component]
+pub fn SelectValue(
+    /// Displayed when no item is selected
+    #[props(into)]
+    placeholder: Option<String>,
+
+    /// Remaining attributes to add to the value `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<SelectState>>();
+    let text = state.read().selected().map(|option| option.text.clone());
+    let placeholder_shown = text.is_none();
+
+    rsx! {
+        span {
+            id: state.read().value_id(),
+            // Clicks should go to the trigger
+            pointer_events: "none",
+            "data-placeholder": placeholder_shown,
+
+            ..attrs,
+
+            if placeholder_shown {
+                {placeholder}
+            } else if children != VNode::empty() {
+                {children}
+            } else {
+                {text}
+            }
+        }
+    }
+}
+
+/// A small icon displayed next to the value, often a chevron. Must live inside `SelectTrigger`
+#[component]
+pub fn SelectIcon(
+    /// Remaining attributes to add to the icon `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    rsx! {
+        span {
+            aria_hidden: true,
+
+            ..attrs,
+
+            if children != VNode::empty() {
+                {children}
+            } else {
+                "▼"
+            }
+        }
+    }
+}
+
+/// Teleports the content to where the `Portal` with the same `name` is rendered
+///
+/// Use this to escape `overflow: hidden` and stacking contexts of the parent elements.
+#[component]
+pub fn SelectPortal(
+    /// Name of the `Portal` to render the content in
+    #[props(into)]
+    name: Option<String>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<SelectState>>();
+
+    rsx! {
+        Teleport {
+            name: name,
+            // Teleported elements lose their context, so it is provided again where they are rendered
+            Provider {
+                inherit: state,
+                {children}
+            }
+        }
+    }
+}
+
+/// Handles the contextual state of the content of a select
+#[derive(Debug)]
+struct SelectContentState {
+    focus: RovingFocus,
+    /// Item that currently has focus
+    focused: Option<String>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+    content: Option<Rc<MountedData>>,
+    viewport: Option<Rc<MountedData>>,
+
+    can_scroll_up: bool,
+    can_scroll_down: bool,
+}
+
+/// Show or hide the scroll buttons depending on whether the viewport can be scrolled any further
+async fn update_scroll(mut content: Signal<SelectContentState>) {
+    let Some(viewport) = content.peek().viewport.clone() else {
+        return;
+    };
+    let (Ok(offset), Ok(size), Ok(rect)) = (
+        viewport.get_scroll_offset().await,
+        viewport.get_scroll_size().await,
+        viewport.get_client_rect().await,
+    ) else {
+        return;
+    };
+
+    let up = offset.y > 0.0;
+    // Scroll positions can be fractional, so allow for a pixel of rounding
+    let down = offset.y + rect.height() < size.height - 1.0;
+    let changed = {
+        let content = content.peek();
+        content.can_scroll_up != up || content.can_scroll_down != down
+    };
+    if changed {
+        let mut content = content.write();
+        content.can_scroll_up = up;
+        content.can_scroll_down = down;
+    }
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(state: Signal<SelectState>, mut content: Signal<SelectContentState>) {
+    if std::mem::replace(&mut content.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        content.write().ordering = false;
+        let id = state.peek().id.clone();
+        let order = dom_order(&id, ITEMS).await;
+        let order: Vec<String> = {
+            let content = content.peek();
+            order.into_iter().filter(|id| content.focus.position(id).is_some()).collect()
+        };
+        if !content.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            content.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Focus the selected item in the content with the given `id`, or the first item when none is selected
+fn focus_selected(id: &str) {
+    document::eval(&format!(r#"
+        requestAnimationFrame(() => {{
+            const content = document.getElementById("{id}");
+            if (!content) return;
+            const item = content.querySelector(`[data-meek-select-item][aria-selected="true"]:not([data-disabled])`)
+                ?? content.querySelector(`{ITEM_SELECTOR}`);
+            (item ?? content).focus({{ preventScroll: true }});
+            item?.scrollIntoView({{ block: "nearest" }});
+        }});
+    "#));
+}
+
+/// The component that pops out when the select is open
+///
+/// With the default `item-aligned` position the content covers the trigger so that the selected item is placed over
+/// the value, scrolling the viewport when the content has to be moved to fit on the screen. With the `popper`
+/// position it is placed next to the trigger and flips and shifts to stay inside of the collision boundary, use the
+/// CSS variables to limit its size.
+///
+/// The items stay rendered, but hidden, while the select is closed so that the value can be displayed.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`, only with the `popper` position
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`, only with the `popper` position
+///
+/// # CSS Variables
+///
+/// Only set with the `popper` position.
+///
+/// - `--meek-popper-available-width`: The width between the trigger and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the trigger and the edge of the boundary
+/// - `--meek-popper-anchor-width`: The width of the trigger
+/// - `--meek-popper-anchor-height`: The height of the trigger
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the trigger
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowDown>`: Moves focus to the next item.
+/// - `<ArrowUp>`: Moves focus to the previous item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last item.
+/// - `<Escape>`: Closes the select and moves focus back to the trigger.
+/// - Typing a character moves focus to the next item whose text starts with the typed characters.
+#[component]
+pub fn SelectContent(
+    /// How the content is positioned. Defaults to `item-aligned`
+    #[props(into, default)]
+    position: SelectPosition,
+    /// The side of the trigger to place the content against with the `popper` position. Defaults to `bottom`
+    #[props(into, default)]
+    side: Side,
+    /// Distance in pixels between the trigger and the content with the `popper` position. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Alignment of the content along the side of the trigger with the `popper` position. Defaults to `start`
+    #[props(into, default = Align::Start)]
+    align: Align,
+    /// Offset in pixels from the `align` position. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the opposite side and shifts along the trigger to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+
+    /// Handler that is run when focus moves back to the trigger after closing
+    ///
+    /// Call `prevent_default` on the event to focus a different element manually
+    oncloseautofocus: Option<EventHandler<FocusScopeEvent>>,
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the select open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the select open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<SelectState>>();
+    let mut content = use_context_provider(|| Signal::new(SelectContentState {
+        focus: RovingFocus::new(Some(Orientation::Vertical), false),
+        focused: None,
+        ordering: false,
+        content: None,
+        viewport: None,
+        can_scroll_up: false,
+        can_scroll_down: false,
+    }));
+
+    let id = use_memo(move || state.read().id.clone());
+    let open = use_memo(move || *state.read().open.read());
+    let is_present = use_presence(id, open);
+
+    // The content may be teleported, so wait until it is in the DOM before managing it
+    let mut mounted = use_signal(|| false);
+    let active = use_memo(move || open() && mounted());
+
+    let onmountautofocus = use_callback(move |evt: FocusScopeEvent| {
+        // The selected item is focused once the content has been positioned
+        evt.prevent_default();
+    });
+    let trapped = use_memo(|| true);
+    use_focus_scope(state.peek().id.clone(), active, trapped, trapped, Some(onmountautofocus), oncloseautofocus);
+
+    let ondismiss = use_callback(move |_| state.write().set_open(false));
+    use_dismissable_layer(
+        state.peek().id.clone(),
+        active,
+        vec![state.peek().trigger_id()],
+        DismissableLayerHandlers {
+            onescapekeydown,
+            onpointerdownoutside,
+            onfocusoutside: None,
+            oninteractoutside: None,
+            ondismiss: Some(ondismiss),
+        },
+    );
+
+    let options = use_memo(use_reactive!(|side, side_offset, align, align_offset, avoid_collisions, collision_padding, collision_boundary| {
+        PopperOptions {
+            side,
+            align,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: 0.0,
+        }
+    }));
+    let popper = position.is_popper();
+    let anchor = use_memo(move || PopperAnchor::Element(state.read().trigger_id()));
+    let popper_id = use_memo(move || state.read().popper_id());
+    let tracking = use_memo(use_reactive!(|popper| popper && is_present() && mounted()));
+    let placement = use_popper(anchor, popper_id, tracking, options);
+    use_context_provider(|| PopperContext { placement });
+
+    // Item aligned content is measured and moved into place before it is shown
+    let mut aligned = use_signal(|| false);
+    use_effect(use_reactive!(|popper| {
+        if !active() {
+            // Stay in place while the exit animations run
+            if !is_present() {
+                aligned.set(false);
+            }
+            return;
+        }
+        if popper {
+            return;
+        }
+        let (trigger, value, wrapper, id, viewport) = {
+            let state = state.peek();
+            (state.trigger_id(), state.value_id(), state.popper_id(), state.id.clone(), state.viewport_id())
+        };
+        spawn(async move {
+            let mut eval = document::eval(&format!(r#"
+                const margin = {CONTENT_MARGIN};
+                const trigger = document.getElementById("{trigger}");
+                const wrapper = document.getElementById("{wrapper}");
+                const content = document.getElementById("{id}");
+                if (!trigger || !wrapper || !content) {{
+                    dioxus.send(false);
+                    return;
+                }}
+                const value = document.getElementById("{value}") ?? trigger;
+                const viewport = document.getElementById("{viewport}") ?? content;
+
+                wrapper.style.height = "";
+                viewport.scrollTop = 0;
+
+                const triggerRect = trigger.getBoundingClientRect();
+                const valueRect = value.getBoundingClientRect();
+                const contentRect = content.getBoundingClientRect();
+                const selected = content.querySelector(`[data-meek-select-item][aria-selected="true"]`)
+                    ?? content.querySelector(`{ITEM_SELECTOR}`);
+                const text = selected?.querySelector("[data-meek-select-item-text]") ?? selected;
+                const textRect = text?.getBoundingClientRect() ?? contentRect;
+
+                // Line the text of the selected item up with the value, while covering the whole trigger
+                const desiredLeft = valueRect.left - (textRect.left - contentRect.left);
+                const minWidth = triggerRect.right - desiredLeft;
+                const width = Math.max(minWidth, contentRect.width);
+                const left = Math.max(margin, Math.min(desiredLeft, innerWidth - margin - width));
+
+                // Center the selected item on the trigger, the viewport is scrolled when the content has to move to fit
+                const height = Math.min(contentRect.height, innerHeight - margin * 2);
+                const itemRect = selected?.getBoundingClientRect();
+                const center = itemRect ? itemRect.top + itemRect.height / 2 - contentRect.top : height / 2;
+                const desiredTop = triggerRect.top + triggerRect.height / 2 - center;
+                const top = Math.max(margin, Math.min(desiredTop, innerHeight - margin - height));
+
+                wrapper.style.left = `${{left}}px`;
+                wrapper.style.top = `${{top}}px`;
+                wrapper.style.minWidth = `${{minWidth}}px`;
+                wrapper.style.height = `${{height}}px`;
+                viewport.scrollTop = top - desiredTop;
+                dioxus.send(true);
+            "#));
+            if eval.recv::<bool>().await.unwrap_or_default() {
+                aligned.set(true);
+                update_scroll(content).await;
+            }
+        });
+    }));
+
+    let ready = use_memo(use_reactive!(|popper| active() && (popper || aligned())));
+    use_effect(move || {
+        if ready() {
+            focus_selected(&id.peek());
+        }
+    });
+
+    let placed = *placement.read();
+    let mut attrs = attrs;
+    if popper {
+        Placement::push_attributes(placed.as_ref(), &mut attrs);
+    }
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        let key = evt.key();
+        let modifiers = evt.modifiers();
+        match key {
+            // Focus stays inside of the select until it is closed
+            Key::Tab => evt.prevent_default(),
+            Key::Character(ref character) if character != " "
+                && character.chars().count() == 1
+                && !modifiers.ctrl()
+                && !modifiers.alt()
+                && !modifiers.meta() =>
+            {
+                let current = content.read().focused.clone();
+                if let Some(target) = typeahead(&id.peek(), ITEM_SELECTOR, character, current.as_deref()).await {
+                    let data = content.read().focus.get(&target);
+                    if let Some(data) = data {
+                        let _ = data.set_focus(true).await;
+                    }
+                }
+            }
+            _ => {
+                let target = {
+                    let content = content.read();
+                    match content.focused.as_deref() {
+                        Some(current) => content.focus.navigate(current, &evt).map(|(_, data)| data),
+                        None => {
+                            let edge = match key {
+                                Key::ArrowDown | Key::Home => content.focus.first(),
+                                Key::ArrowUp | Key::End => content.focus.last(),
+                                _ => None,
+                            };
+                            edge.and_then(|key| content.focus.get(key))
+                        }
+                    }
+                };
+                if let Some(data) = target {
+                    evt.prevent_default();
+                    let _ = data.set_focus(true).await;
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            id: popper_id,
+            position: "fixed",
+            display: if is_present() { "flex" } else { "none" },
+            flex_direction: "column",
+            left: if popper { Some("0px") } else { None },
+            top: if popper { Some("0px") } else { None },
+            min_width: if popper { Some("max-content") } else { None },
+            transform: if popper { Some(Placement::transform(placed.as_ref())) } else { None },
+            visibility: if popper || aligned() { "visible" } else { "hidden" },
+
+            div {
+                role: "listbox",
+                id: id,
+                tabindex: -1,
+                display: "flex",
+                flex_direction: "column",
+                box_sizing: "border-box",
+                max_height: if popper { None } else { Some("100%") },
+                "data-state": if open() { "open" } else { "closed" },
+                "data-side": if popper { Some(placed.map(|p| p.side).unwrap_or(side)) } else { None },
+                "data-align": if popper { Some(placed.map(|p| p.align).unwrap_or(align)) } else { None },
+
+                onkeydown: onkeydown,
+                onmounted: move |v: Event<MountedData>| {
+                    content.write().content = Some(v.data());
+                    mounted.set(true);
+                },
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// The scrollable area of the content that contains the items. Must live inside `SelectContent`
+///
+/// The native scrollbar is hidden, the scroll buttons are shown instead.
+#[component]
+pub fn SelectViewport(
+    /// Remaining attributes to add to the viewport `div` tag
+    #[props(extends = GlobalAttributes)]
+    mut attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<SelectState>>();
+    let mut content = use_context::<Signal<SelectContentState>>();
+    attrs.push(Attribute::new("scrollbar-width", "none", Some("style"), false));
+
+    rsx! {
+        div {
+            role: "presentation",
+            id: state.read().viewport_id(),
+            position: "relative",
+            flex: 1,
+            min_height: "0px",
+            overflow: "auto",
+
+            onscroll: move |_| update_scroll(content),
+            onmounted: move |v: Event<MountedData>| {
+                content.write().viewport = Some(v.data());
+                update_scroll(content)
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Render a button that scrolls the viewport while the pointer is over it
+///
+/// Only rendered when the viewport can be scrolled in that direction
+fn render_scroll_button(up: bool, attrs: Vec<Attribute>, children: Element) -> Element {
+    let state = use_context::<Signal<SelectState>>();
+    let content = use_context::<Signal<SelectContentState>>();
+    let mut timer = use_signal(|| None::<Task>);
+
+    let can_scroll = move || {
+        let content = content.peek();
+        if up { content.can_scroll_up } else { content.can_scroll_down }
+    };
+    let mut start = move || {
+        if timer.peek().is_some() {
+            return;
+        }
+        let viewport = state.peek().viewport_id();
+        let direction = if up { -1 } else { 1 };
+        timer.set(Some(spawn(async move {
+            while can_scroll() {
+                document::eval(&format!(r#"
+                    const viewport = document.getElementById("{viewport}");
+                    const item = viewport?.querySelector("[data-meek-select-item]");
+                    if (viewport) viewport.scrollTop += {direction} * (item?.offsetHeight ?? 16);
+                "#));
+                sleep(SCROLL_INTERVAL).await;
+            }
+            timer.set(None);
+        })));
+    };
+    let mut stop = move || {
+        if let Some(task) = timer.take() {
+            task.cancel();
+        }
+    };
+
+    let visible = {
+        let content = content.read();
+        if up { content.can_scroll_up } else { content.can_scroll_down }
+    };
+
+    rsx! {
+        if visible {
+            div {
+                aria_hidden: true,
+                flex_shrink: 0,
+
+                onpointerdown: move |evt| evt.prevent_default(),
+                onpointermove: move |_| start(),
+                onpointerleave: move |_| stop(),
+
+                ..attrs,
+
+                if children != VNode::empty() {
+                    {children}
+                } else {
+                    if up { "▲" } else { "▼" }
+                }
+            }
+        }
+    }
+}
+
+/// Scrolls the viewport up while the pointer is over it. Must live inside `SelectContent`, before `SelectViewport`
+///
+/// Only rendered when the viewport can be scrolled up.
+#[component]
+pub fn SelectScrollUpButton(
+    /// Remaining attributes to add to the button `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_scroll_button(true, attrs, children)
+}
+
+/// Scrolls the viewport down while the pointer is over it. Must live inside `SelectContent`, after `SelectViewport`
+///
+/// Only rendered when the viewport can be scrolled down.
+#[component]
+pub fn SelectScrollDownButton(
+    /// Remaining attributes to add to the button `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_scroll_button(false, attrs, children)
+}
+
+/// Handles the contextual state of a select item
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SelectItemState {
+    id: Signal<String>,
+    selected: Memo<bool>,
+    /// Text read from `SelectItemText`, unless a `text_value` is passed to the item
+    text: Signal<Option<String>>,
+    has_text_value: Memo<bool>,
+}
+
+/// An item in the select
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-highlighted]`: Present when highlighted
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Selects the item and closes the select.
+/// - `<Enter>`: Selects the item and closes the select.
+#[component]
+pub fn SelectItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` event is run and what is submitted with a form
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Text used for typeahead and displayed by `SelectValue`. Defaults to the text content of `SelectItemText`
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<SelectState>>();
+    let mut content = use_context::<Signal<SelectContentState>>();
+    let disabled = disabled.unwrap_or_default();
+
+    let id = use_signal(create_id);
+    let text = use_signal(|| None::<String>);
+    let has_text_value = use_memo(use_reactive!(|text_value| text_value.is_some()));
+    let current = use_memo(use_reactive!(|value| value));
+    let selected = use_memo(move || state.read().is_selected(current.read().as_str()));
+    use_context_provider(|| SelectItemState { id, selected, text, has_text_value });
+
+    use_effect(use_reactive!(|value, text_value, disabled| {
+        let text = text_value.or_else(|| text.read().clone()).unwrap_or_default();
+        state.write().add_option(SelectOption { key: id.peek().clone(), value, text, disabled });
+    }));
+
+    use_effect(use_reactive!(|disabled| {
+        content.write().focus.set_disabled(id.peek().as_str(), disabled);
+    }));
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.remove_option(id.peek().as_str());
+        }
+        if let Ok(mut content) = content.try_write() {
+            content.focus.remove_item(id.peek().as_str());
+        }
+    });
+
+    let highlighted = content.read().focused.as_deref() == Some(id.read().as_str());
+    let mut choose = move || {
+        if !disabled {
+            let value = current.peek().clone();
+            let mut state = state.write();
+            state.select(value);
+            state.set_open(false);
+        }
+    };
+
+    rsx! {
+        div {
+            role: "option",
+            id: id,
+            tabindex: if disabled { None } else { Some(-1) },
+            aria_labelledby: "{id}:text",
+            aria_selected: selected(),
+            aria_disabled: disabled,
+            "data-meek-select-item": true,
+            "data-meek-text-value": text_value,
+            "data-state": if selected() { "checked" } else { "unchecked" },
+            "data-highlighted": highlighted,
+            "data-disabled": disabled,
+
+            onfocus: move |_| content.write().focused = Some(id.peek().clone()),
+            onblur: move |_| {
+                let mut content = content.write();
+                if content.focused.as_deref() == Some(id.peek().as_str()) {
+                    content.focused = None;
+                }
+            },
+            onpointermove: move |evt: Event<PointerData>| async move {
+                if disabled || highlighted || evt.pointer_type() != "mouse" {
+                    return;
+                }
+                let data = content.read().focus.get(id.read().as_str());
+                if let Some(data) = data {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onpointerleave: move |evt: Event<PointerData>| async move {
+                if evt.pointer_type() != "mouse" {
+                    return;
+                }
+                // Remove the highlight by focusing the content
+                let data = content.read().content.clone();
+                if let Some(data) = data {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onclick: move |_| choose(),
+            onkeydown: move |evt| {
+                if matches!(evt.key(), Key::Enter) || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    choose();
+                }
+            },
+            onmounted: move |v: Event<MountedData>| {
+                content.write().focus.add_item(id.read().as_str(), v.data());
+                schedule_order(state, content);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The text of the item. Must live inside `SelectItem`
+///
+/// Its text content is displayed by `SelectValue` when the item is selected, unless the item has a `text_value`.
+#[component]
+pub fn SelectItemText(
+    /// Remaining attributes to add to the text `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut item = use_context::<SelectItemState>();
+    let id = format!("{}:text", item.id.read());
+
+    rsx! {
+        span {
+            id: id.as_str(),
+            "data-meek-select-item-text": true,
+
+            onmounted: {
+                let id = id.clone();
+                move |_| {
+                    let id = id.clone();
+                    async move {
+                        if *item.has_text_value.peek() {
+                            return;
+                        }
+                        let mut eval = document::eval(&format!(r#"
+                            dioxus.send(document.getElementById("{id}")?.textContent?.trim() ?? "");
+                        "#));
+                        if let Ok(text) = eval.recv::<String>().await {
+                            item.text.set(Some(text));
+                        }
+                    }
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Renders when the item is selected. Must live inside `SelectItem`
+#[component]
+pub fn SelectItemIndicator(
+    /// Remaining attributes to add to the indicator `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let item = use_context::<SelectItemState>();
+
+    rsx! {
+        if (item.selected)() {
+            span {
+                aria_hidden: true,
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Shares the id of the label of a group
+#[derive(Debug, Clone, PartialEq)]
+struct SelectGroupState {
+    label_id: String,
+}
+
+/// Groups multiple items together
+#[component]
+pub fn SelectGroup(
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let group = use_context_provider(|| SelectGroupState { label_id: create_id() });
+
+    rsx! {
+        div {
+            role: "group",
+            aria_labelledby: group.label_id,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A label for a group of items. It can't be focused with the arrow keys
+#[component]
+pub fn SelectLabel(
+    /// Remaining attributes to add to the label `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let group = try_use_context::<SelectGroupState>();
+
+    rsx! {
+        div {
+            id: group.map(|group| group.label_id),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Visually separates items in the select
+#[component]
+pub fn SelectSeparator(
+    /// Remaining attributes to add to the separator `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+) -> Element {
+    rsx! {
+        div {
+            aria_hidden: true,
+
+            ..attrs,
+        }
+    }
+}
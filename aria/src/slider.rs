@@ -0,0 +1,658 @@
+use std::rc::Rc;
+
+use dioxus::{html::geometry::{ClientPoint, PixelsRect}, prelude::*};
+
+use crate::{create_id, Orientation, OptionalMaybeSignal};
+
+/// Handles the contextual state of a slider
+#[derive(Debug)]
+struct SliderState {
+    id: String,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub min_steps_between_thumbs: f64,
+    pub orientation: Orientation,
+    pub inverted: bool,
+    pub disabled: bool,
+    pub name: Option<String>,
+
+    /// Ids of the rendered thumbs and their mounted data, in the order of their values
+    thumbs: Vec<(String, Option<Rc<MountedData>>)>,
+    root: Option<Rc<MountedData>>,
+    /// Bounds of the slider captured when a drag starts
+    rect: Option<PixelsRect>,
+    /// Thumb that is currently being dragged
+    active: Option<usize>,
+
+    values: Signal<Vec<f64>>,
+    onchange: Option<EventHandler<Vec<f64>>>,
+    onvaluecommit: Option<EventHandler<Vec<f64>>>,
+}
+
+impl SliderState {
+    /// Lowest and highest value of the slider, swapped when `max` is below `min`
+    fn range(min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+        let (min, max) = (min.unwrap_or(0.0), max.unwrap_or(100.0));
+        (min.min(max), min.max(max))
+    }
+
+    /// Interval that values snap to, ignoring steps that aren't positive
+    fn step(step: Option<f64>) -> f64 {
+        step.filter(|step| *step > 0.0).unwrap_or(1.0)
+    }
+
+    /// Sort the values of the thumbs from lowest to highest
+    fn sorted(mut values: Vec<f64>) -> Vec<f64> {
+        values.sort_by(f64::total_cmp);
+        values
+    }
+
+    /// Register a thumb and get its id
+    fn add_thumb(&mut self) -> String {
+        let id = create_id();
+        self.thumbs.push((id.clone(), None));
+        id
+    }
+
+    /// Unregister a thumb and remove its value, the thumbs after it take over the values after it
+    fn remove_thumb(&mut self, id: &str) {
+        let Some(index) = self.thumb_index(id) else { return };
+        self.thumbs.remove(index);
+        if index < self.values.peek().len() {
+            self.values.write().remove(index);
+        }
+    }
+
+    /// Index of a thumb, which is also the index of its value
+    fn thumb_index(&self, id: &str) -> Option<usize> {
+        self.thumbs.iter().position(|(thumb, _)| thumb == id)
+    }
+
+    /// Get the value of a thumb
+    fn value(&self, index: usize) -> Option<f64> {
+        self.values.read().get(index).copied()
+    }
+
+    /// Percentage of the track that a value is from the start of the slider
+    fn percent(&self, value: f64) -> f64 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((value - self.min) / (self.max - self.min) * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// Snap a value to the closest step
+    fn snap(&self, value: f64) -> f64 {
+        let snapped = ((value - self.min) / self.step).round() * self.step + self.min;
+        // Avoid floating point noise like `0.30000000000000004`
+        let decimals = self.step.to_string().split('.').nth(1).map(|d| d.len()).unwrap_or_default() as i32;
+        let factor = 10f64.powi(decimals);
+        ((snapped * factor).round() / factor).clamp(self.min, self.max)
+    }
+
+    /// Lowest and highest value a thumb can have while keeping the minimum distance to its neighbors
+    fn bounds(&self, index: usize) -> (f64, f64) {
+        let values = self.values.peek();
+        let gap = self.min_steps_between_thumbs * self.step;
+        let low = index.checked_sub(1).and_then(|i| values.get(i)).map(|v| v + gap).unwrap_or(self.min);
+        let high = values.get(index + 1).map(|v| v - gap).unwrap_or(self.max);
+        (low, high)
+    }
+
+    /// Update the value of a thumb, returns whether the value changed
+    ///
+    /// The value is left unchanged when the neighbors of the thumb are too close to keep the minimum distance.
+    fn set_value(&mut self, index: usize, value: f64) -> bool {
+        let (low, high) = self.bounds(index);
+        if low > high {
+            return false;
+        }
+        let value = self.snap(value).clamp(low, high);
+
+        if self.values.peek().get(index).is_none_or(|current| *current == value) {
+            return false;
+        }
+
+        self.values.write()[index] = value;
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(self.values.peek().clone());
+        }
+        true
+    }
+
+    /// Run `onvaluecommit` with the current values
+    fn commit(&self) {
+        if let Some(onvaluecommit) = self.onvaluecommit.as_ref() {
+            onvaluecommit.call(self.values.peek().clone());
+        }
+    }
+
+    /// Convert a pointer position into a slider value
+    fn value_from_point(&self, point: ClientPoint) -> Option<f64> {
+        let rect = self.rect?;
+        let ratio = match self.orientation {
+            Orientation::Horizontal if rect.size.width > 0.0 => (point.x - rect.origin.x) / rect.size.width,
+            Orientation::Vertical if rect.size.height > 0.0 => 1.0 - (point.y - rect.origin.y) / rect.size.height,
+            _ => return None,
+        };
+        let ratio = if self.inverted { 1.0 - ratio } else { ratio };
+        Some(self.min + ratio.clamp(0.0, 1.0) * (self.max - self.min))
+    }
+
+    /// Index of the thumb closest to the value
+    fn closest(&self, value: f64) -> usize {
+        self.values.peek()
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - value).abs().total_cmp(&(*b - value).abs()))
+            .map(|(index, _)| index)
+            .unwrap_or_default()
+    }
+
+    /// Whether the key moves the value towards the minimum
+    fn is_back_key(&self, key: &Key) -> bool {
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) | (Orientation::Vertical, false) => matches!(key, Key::ArrowLeft | Key::ArrowDown),
+            (Orientation::Horizontal, true) => matches!(key, Key::ArrowRight | Key::ArrowDown),
+            (Orientation::Vertical, true) => matches!(key, Key::ArrowUp | Key::ArrowLeft),
+        }
+    }
+
+    /// CSS properties of the start and end edges of the track
+    fn edges(&self) -> (&'static str, &'static str) {
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) => ("left", "right"),
+            (Orientation::Horizontal, true) => ("right", "left"),
+            (Orientation::Vertical, false) => ("bottom", "top"),
+            (Orientation::Vertical, true) => ("top", "bottom"),
+        }
+    }
+}
+
+/// Slider
+///
+/// An input where the user selects a value, or a range of values with multiple thumbs, from within a given range.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn Slider(
+    /// Value of each thumb
+    ///
+    /// When a signal is passed it is kept in sync with the values
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<f64>>,
+    /// Default value of each thumb
+    default: Option<Vec<f64>>,
+    /// Handler that is run when the values change
+    onchange: Option<EventHandler<Vec<f64>>>,
+    /// Handler that is run when the user finishes changing the values, e.g. on pointer up
+    onvaluecommit: Option<EventHandler<Vec<f64>>>,
+
+    /// Minimum value. Defaults to `0`
+    min: Option<f64>,
+    /// Maximum value. Defaults to `100`
+    max: Option<f64>,
+    /// Interval that values snap to. Defaults to `1`
+    step: Option<f64>,
+    /// Minimum number of steps that are kept between thumbs. Defaults to `0`
+    min_steps_between_thumbs: Option<f64>,
+    /// Direction of the slider. Defaults to `horizontal`
+    orientation: Option<Orientation>,
+    /// Whether the slider starts at the opposite end of the track
+    inverted: Option<bool>,
+    disabled: Option<bool>,
+    /// Name of the slider when submitted as part of a form
+    ///
+    /// Sliders with multiple thumbs submit their values as `name[]`
+    #[props(into)]
+    name: Option<String>,
+
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes that are to be added to the slider `span` container
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let (low, high) = SliderState::range(min, max);
+    let values = value.clone().as_signal(SliderState::sorted(default.clone().unwrap_or(vec![low])));
+    let mut state = use_context_provider(|| Signal::new(SliderState {
+        id: id.unwrap_or(create_id()),
+        min: low,
+        max: high,
+        step: SliderState::step(step),
+        min_steps_between_thumbs: min_steps_between_thumbs.unwrap_or_default(),
+        orientation: orientation.unwrap_or(Orientation::Horizontal),
+        inverted: inverted.unwrap_or_default(),
+        disabled: disabled.unwrap_or_default(),
+        name: name.clone(),
+        thumbs: Vec::new(),
+        root: None,
+        rect: None,
+        active: None,
+        values,
+        onchange,
+        onvaluecommit,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            let mut values = state.peek().values;
+            let value = SliderState::sorted(value);
+            if *values.peek() != value {
+                values.set(value);
+            }
+        }
+    }));
+
+    // Values passed through a signal can be set out of order
+    use_effect(move || {
+        let mut values = state.peek().values;
+        if !values.read().is_sorted_by(|a, b| a <= b) {
+            values.write().sort_by(f64::total_cmp);
+        }
+    });
+
+    use_effect(use_reactive!(|min, max, step, min_steps_between_thumbs, orientation, inverted, disabled, name| {
+        let mut state = state.write();
+        (state.min, state.max) = SliderState::range(min, max);
+        state.step = SliderState::step(step);
+        state.min_steps_between_thumbs = min_steps_between_thumbs.unwrap_or_default();
+        state.orientation = orientation.unwrap_or(Orientation::Horizontal);
+        state.inverted = inverted.unwrap_or_default();
+        state.disabled = disabled.unwrap_or_default();
+        state.name = name;
+    }));
+
+    let onpointerdown = move |evt: Event<PointerData>| async move {
+        if state.read().disabled {
+            return;
+        }
+
+        let point = evt.client_coordinates();
+        let pointer = evt.pointer_id();
+        let (id, root) = {
+            let state = state.read();
+            (state.id.clone(), state.root.clone())
+        };
+
+        let Some(root) = root else { return };
+        let Ok(rect) = root.get_client_rect().await else { return };
+
+        // Keep receiving pointer events while dragging outside of the slider
+        document::eval(&format!(r#"document.getElementById("{id}")?.setPointerCapture({pointer})"#));
+
+        let thumb = {
+            let mut state = state.write();
+            state.rect = Some(rect);
+            let Some(value) = state.value_from_point(point) else { return };
+            let index = state.closest(value);
+            state.active = Some(index);
+            state.set_value(index, value);
+            state.thumbs.get(index).and_then(|(_, thumb)| thumb.clone())
+        };
+
+        if let Some(thumb) = thumb {
+            let _ = thumb.set_focus(true).await;
+        }
+    };
+
+    let onpointermove = move |evt: Event<PointerData>| {
+        let Some(index) = state.peek().active else {
+            return;
+        };
+        let mut state = state.write();
+        if let Some(value) = state.value_from_point(evt.client_coordinates()) {
+            state.set_value(index, value);
+        }
+    };
+
+    let onpointerup = move |_: Event<PointerData>| {
+        let mut state = state.write();
+        if state.active.take().is_some() {
+            state.rect = None;
+            state.commit();
+        }
+    };
+
+    rsx! {
+        span {
+            id: state.read().id.as_str(),
+            position: "relative",
+            touch_action: "none",
+            aria_disabled: state.read().disabled,
+            "data-orientation": state.read().orientation,
+            "data-disabled": state.read().disabled,
+
+            onpointerdown: onpointerdown,
+            onpointermove: onpointermove,
+            onpointerup: onpointerup,
+            onpointercancel: onpointerup,
+            onmounted: move |v: Event<MountedData>| state.write().root = Some(v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The track that contains the `SliderRange`
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn SliderTrack(
+    /// Remaining attributes to add to the track `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<SliderState>>();
+
+    rsx! {
+        span {
+            position: "relative",
+            "data-orientation": state.read().orientation,
+            "data-disabled": state.read().disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The range part. Must live inside `SliderTrack`
+///
+/// Spans from the start of the track to the thumb, or between the first and last thumb.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn SliderRange(
+    /// Remaining attributes to add to the range `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<SliderState>>();
+
+    let (start, end) = {
+        let state = state.read();
+        let values = state.values.read();
+        let percents = values.iter().map(|value| state.percent(*value));
+        let start = if values.len() > 1 { percents.clone().fold(100.0, f64::min) } else { 0.0 };
+        let end = percents.fold(0.0, f64::max);
+        (start, end)
+    };
+
+    let mut attrs = attrs;
+    let (from, to) = state.read().edges();
+    attrs.push(Attribute::new(from, format!("{start}%"), Some("style"), false));
+    attrs.push(Attribute::new(to, format!("{}%", 100.0 - end), Some("style"), false));
+
+    rsx! {
+        span {
+            position: "absolute",
+            "data-orientation": state.read().orientation,
+            "data-disabled": state.read().disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A draggable thumb. One thumb is rendered for each value of the slider
+///
+/// A hidden native `input` is rendered next to the thumb so that its value is submitted with a form.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<ArrowRight>`: Increments/decrements by the `step` value depending on `orientation`.
+/// - `<ArrowLeft>`: Increments/decrements by the `step` value depending on `orientation`.
+/// - `<ArrowUp>`: Increases the value by the `step` amount.
+/// - `<ArrowDown>`: Decreases the value by the `step` amount.
+/// - `<PageUp>`: Increases the value by a larger `step`.
+/// - `<PageDown>`: Decreases the value by a larger `step`.
+/// - `<Shift+Arrow>`: Increases/decreases the value by a larger `step`.
+/// - `<Home>`: Sets the value to its minimum.
+/// - `<End>`: Sets the value to its maximum.
+#[component]
+pub fn SliderThumb(
+    /// Remaining attributes to add to the thumb `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<SliderState>>();
+    let id = use_hook(|| state.write().add_thumb());
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                state.remove_thumb(&id);
+            }
+        }
+    });
+
+    let Some(value) = state.read().thumb_index(&id).and_then(|index| state.read().value(index)) else {
+        return VNode::empty();
+    };
+
+    let onkeydown = {
+        let id = id.clone();
+        move |evt: Event<KeyboardData>| {
+            let mut state = state.write();
+            if state.disabled {
+                return;
+            }
+
+            let Some(index) = state.thumb_index(&id) else { return };
+            let Some(current) = state.value(index) else { return };
+            let key = evt.key();
+            let multiplier = if evt.modifiers().shift() || matches!(key, Key::PageUp | Key::PageDown) { 10.0 } else { 1.0 };
+            let step = state.step * multiplier;
+
+            let next = match key {
+                Key::Home => state.min,
+                Key::End => state.max,
+                Key::PageDown => current - step,
+                Key::PageUp => current + step,
+                Key::ArrowUp | Key::ArrowDown | Key::ArrowLeft | Key::ArrowRight => {
+                    if state.is_back_key(&key) { current - step } else { current + step }
+                }
+                _ => return,
+            };
+
+            evt.prevent_default();
+            if state.set_value(index, next) {
+                state.commit();
+            }
+        }
+    };
+
+    let mut attrs = attrs;
+    let percent = state.read().percent(value);
+    attrs.push(Attribute::new(state.read().edges().0, format!("{percent}%"), Some("style"), false));
+
+    let (count, name) = {
+        let state = state.read();
+        let count = state.values.read().len();
+        (count, state.name.clone())
+    };
+    let name = name.map(|name| if count > 1 { format!("{name}[]") } else { name });
+
+    rsx! {
+        span {
+            role: "slider",
+            tabindex: if state.read().disabled { None } else { Some(0) },
+            aria_valuemin: state.read().min,
+            aria_valuemax: state.read().max,
+            aria_valuenow: value,
+            aria_orientation: state.read().orientation,
+            aria_disabled: state.read().disabled,
+            "data-orientation": state.read().orientation,
+            "data-disabled": state.read().disabled,
+
+            position: "absolute",
+            transform: match state.read().edges().0 {
+                "left" => "translateX(-50%)",
+                "right" => "translateX(50%)",
+                "bottom" => "translateY(50%)",
+                _ => "translateY(-50%)",
+            },
+
+            onkeydown: onkeydown,
+            onmounted: move |v: Event<MountedData>| {
+                if let Some((_, thumb)) = state.write().thumbs.iter_mut().find(|(thumb, _)| *thumb == id) {
+                    *thumb = Some(v.data());
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+        input {
+            r#type: "hidden",
+            value: value,
+            name,
+            disabled: state.read().disabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `test` with a slider state from `0` to `10` in steps of `0.5` that keeps one step between thumbs
+    fn with_state(values: Vec<f64>, test: impl FnOnce(&mut SliderState)) {
+        let dom = VirtualDom::prebuilt(VNode::empty);
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| {
+            let mut state = SliderState {
+                id: create_id(),
+                min: 0.0,
+                max: 10.0,
+                step: 0.5,
+                min_steps_between_thumbs: 1.0,
+                orientation: Orientation::Horizontal,
+                inverted: false,
+                disabled: false,
+                name: None,
+                thumbs: Vec::new(),
+                root: None,
+                rect: None,
+                active: None,
+                values: Signal::new(values),
+                onchange: None,
+                onvaluecommit: None,
+            };
+            test(&mut state);
+        }));
+    }
+
+    #[test]
+    fn range_swaps_crossed_bounds() {
+        assert_eq!(SliderState::range(None, None), (0.0, 100.0));
+        assert_eq!(SliderState::range(Some(10.0), Some(-5.0)), (-5.0, 10.0));
+        assert_eq!(SliderState::range(Some(150.0), None), (100.0, 150.0));
+    }
+
+    #[test]
+    fn step_ignores_non_positive_steps() {
+        assert_eq!(SliderState::step(Some(0.25)), 0.25);
+        assert_eq!(SliderState::step(Some(0.0)), 1.0);
+        assert_eq!(SliderState::step(Some(-2.0)), 1.0);
+    }
+
+    #[test]
+    fn snap_rounds_to_step_within_range() {
+        with_state(vec![0.0], |state| {
+            assert_eq!(state.snap(2.3), 2.5);
+            assert_eq!(state.snap(2.2), 2.0);
+            assert_eq!(state.snap(-3.0), 0.0);
+            assert_eq!(state.snap(12.0), 10.0);
+        });
+        with_state(vec![0.0], |state| {
+            state.step = 0.1;
+            assert_eq!(state.snap(0.3), 0.3);
+        });
+    }
+
+    #[test]
+    fn bounds_keep_distance_to_neighbors() {
+        with_state(vec![2.0, 5.0, 8.0], |state| {
+            assert_eq!(state.bounds(0), (0.0, 4.5));
+            assert_eq!(state.bounds(1), (2.5, 7.5));
+            assert_eq!(state.bounds(2), (5.5, 10.0));
+        });
+    }
+
+    #[test]
+    fn set_value_clamps_to_bounds() {
+        with_state(vec![2.0, 5.0], |state| {
+            assert!(state.set_value(0, 9.0));
+            assert_eq!(*state.values.peek(), vec![4.5, 5.0]);
+            assert!(state.set_value(1, 20.0));
+            assert_eq!(*state.values.peek(), vec![4.5, 10.0]);
+            assert!(state.set_value(1, -1.0));
+            assert_eq!(*state.values.peek(), vec![4.5, 5.0]);
+        });
+    }
+
+    #[test]
+    fn set_value_ignores_unchanged_and_missing_thumbs() {
+        with_state(vec![2.0], |state| {
+            assert!(!state.set_value(0, 2.1));
+            assert!(!state.set_value(1, 5.0));
+            assert_eq!(*state.values.peek(), vec![2.0]);
+        });
+    }
+
+    #[test]
+    fn set_value_leaves_crossed_bounds_unchanged() {
+        with_state(vec![5.0, 5.2, 5.4], |state| {
+            assert!(!state.set_value(1, 3.0));
+            assert_eq!(*state.values.peek(), vec![5.0, 5.2, 5.4]);
+        });
+    }
+
+    #[test]
+    fn remove_thumb_removes_its_value() {
+        with_state(vec![2.0, 5.0, 8.0], |state| {
+            let thumbs: Vec<String> = (0..3).map(|_| state.add_thumb()).collect();
+            state.remove_thumb(&thumbs[1]);
+            assert_eq!(*state.values.peek(), vec![2.0, 8.0]);
+            assert_eq!(state.thumb_index(&thumbs[2]), Some(1));
+            assert_eq!(state.bounds(0), (0.0, 7.5));
+
+            state.remove_thumb(&thumbs[1]);
+            assert_eq!(*state.values.peek(), vec![2.0, 8.0]);
+        });
+    }
+}
@@ -0,0 +1,140 @@
+use dioxus::prelude::*;
+
+/// Handles the contextual state of a switch
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SwitchState {
+    checked: Signal<bool>,
+    disabled: Signal<bool>,
+}
+
+/// A control that allows the user to toggle between checked and not checked
+///
+/// A hidden native `input` is rendered next to the switch so that its value is submitted with a form.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Space>`: Toggles the switch.
+/// - `<Enter>`: Toggles the switch.
+#[component]
+pub fn Switch(
+    /// Passed state whether the switch is checked
+    checked: Option<bool>,
+    /// Default checked state of the switch
+    default: Option<bool>,
+    /// Event handler for when the checked state changes
+    onchange: Option<EventHandler<bool>>,
+
+    #[props(into)]
+    aria_labelledby: Option<String>,
+    #[props(into)]
+    aria_label: Option<String>,
+
+    /// Name of the switch when submitted as part of a form
+    #[props(into)]
+    name: Option<String>,
+    /// Value submitted with a form when the switch is checked. Defaults to `"on"`
+    #[props(into)]
+    value: Option<String>,
+    /// Whether the switch must be checked before the owning form can be submitted
+    required: Option<bool>,
+    disabled: Option<bool>,
+
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_signal(|| checked.or(default).unwrap_or_default());
+    use_effect(use_reactive!(|checked| {
+        if let Some(checked) = checked {
+            state.set(checked);
+        }
+    }));
+
+    let mut is_disabled = use_signal(|| disabled.unwrap_or_default());
+    use_effect(use_reactive!(|disabled| is_disabled.set(disabled.unwrap_or_default())));
+
+    use_context_provider(|| SwitchState { checked: state, disabled: is_disabled });
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "switch",
+            aria_label,
+            aria_labelledby,
+
+            "data-state": if state() { "checked" } else { "unchecked" },
+            "data-disabled": disabled.unwrap_or_default(),
+            aria_checked: state(),
+            aria_required: required,
+            value: value.clone().unwrap_or("on".to_string()),
+
+            disabled,
+            aria_disabled: disabled,
+
+            onclick: move |_| {
+                state.toggle();
+                if let Some(onchange) = onchange.as_ref() {
+                    onchange.call(state());
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+        input {
+            r#type: "checkbox",
+            aria_hidden: true,
+            tabindex: -1,
+            value: value.unwrap_or("on".to_string()),
+            checked: state(),
+            name,
+            required,
+            disabled,
+
+            transform: "translateX(-100%)",
+            position: "absolute",
+            pointer_events: "none",
+            opacity: 0,
+            margin: "0px",
+            width: "0px",
+            height: "0px",
+        }
+    }
+}
+
+/// The thumb that is used to visually indicate whether the switch is on or off
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"checked"` | `"unchecked"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn SwitchThumb(
+    /// Remaining attributes to add to the thumb `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let context = use_context::<SwitchState>();
+
+    rsx! {
+        span {
+            "data-state": if (context.checked)() { "checked" } else { "unchecked" },
+            "data-disabled": (context.disabled)(),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use dioxus::prelude::*;
+
+use crate::{create_id, dom_order, presence::use_presence, roving_focus::RovingFocus, Orientation, OptionalMaybeSignal};
+
+/// Selector matching the triggers of the tabs
+const TRIGGERS: &str = "[data-meek-tabs-trigger]";
+
+/// When a tab is activated during keyboard navigation
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum ActivationMode {
+    /// Tabs are activated as soon as they receive focus
+    #[default]
+    Automatic,
+    /// Tabs are activated when they are clicked or `<Space>`/`<Enter>` is pressed
+    Manual,
+}
+
+impl<A: AsRef<str>> From<A> for ActivationMode {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "automatic" => Self::Automatic,
+            "manual" => Self::Manual,
+            other => panic!("unknown activation mode: '{other}'")
+        }
+    }
+}
+
+/// Handles the contextual state of a tabs group
+#[derive(Debug)]
+struct TabsState {
+    id: String,
+    pub orientation: Orientation,
+    pub activation_mode: ActivationMode,
+
+    focus: RovingFocus,
+    /// Whether the triggers are about to be sorted in the order they are rendered
+    ordering: bool,
+
+    current: Signal<String>,
+    onchange: Option<EventHandler<String>>,
+}
+
+impl TabsState {
+    fn new(
+        id: Option<String>,
+        orientation: Option<Orientation>,
+        activation_mode: ActivationMode,
+        current: Signal<String>,
+        onchange: Option<EventHandler<String>>,
+    ) -> Self {
+        let orientation = orientation.unwrap_or(Orientation::Horizontal);
+        Self {
+            id: id.unwrap_or(create_id()),
+            orientation,
+            activation_mode,
+            focus: RovingFocus::new(Some(orientation), true),
+            ordering: false,
+            current,
+            onchange,
+        }
+    }
+
+    /// Get the formatted trigger id for a tab
+    fn trigger_id(&self, value: &str) -> String {
+        format!("meek-tabs-trigger-{}-{value}", self.id)
+    }
+
+    /// Get the formatted content id for a tab
+    fn content_id(&self, value: &str) -> String {
+        format!("meek-tabs-content-{}-{value}", self.id)
+    }
+
+    /// Check if a tab is the active tab
+    pub fn is_active(&self, value: impl AsRef<str>) -> bool {
+        *self.current.read() == value.as_ref()
+    }
+
+    /// Whether the tab is the one that is reached when tabbing into the tab list
+    fn is_tab_stop(&self, value: &str) -> bool {
+        let current = self.current.read();
+        if self.focus.keys().any(|key| key == current.as_str()) {
+            *current == value
+        } else {
+            self.focus.first() == Some(value)
+        }
+    }
+
+    /// Activate a tab
+    pub fn select(&mut self, value: impl AsRef<str>) {
+        let value = value.as_ref();
+        if !self.is_active(value) {
+            self.current.set(value.to_string());
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(value.to_string());
+            }
+        }
+    }
+}
+
+/// Tabs
+///
+/// A set of layered sections of content, known as tab panels, that are displayed one at a time.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+#[component]
+pub fn Tabs(
+    /// The active tab
+    ///
+    /// When a signal is passed it is kept in sync with the active tab
+    #[props(into, default)]
+    value: OptionalMaybeSignal<String>,
+    /// The tab that is active by default
+    #[props(into)]
+    default: Option<String>,
+    /// Direction of the tab list. Defaults to `horizontal`
+    orientation: Option<Orientation>,
+    /// Whether tabs are activated when focused or when clicked
+    #[props(into, default = ActivationMode::Automatic)]
+    activation_mode: ActivationMode,
+
+    /// Handler that is run when the active tab changes
+    onchange: Option<EventHandler<String>>,
+
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes that are to be added to the tabs `div` container
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(default.unwrap_or_default());
+    let state = use_context_provider(|| Signal::new(TabsState::new(id, orientation, activation_mode, current, onchange)));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            let mut current = state.peek().current;
+            if *current.peek() != value {
+                current.set(value);
+            }
+        }
+    }));
+
+    rsx! {
+        div {
+            id: state.read().id.as_str(),
+            "data-orientation": state.read().orientation,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Sort the triggers in the order they are rendered, which can differ from the order they mounted in
+///
+/// Triggers that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<TabsState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.id.clone()
+        };
+        let order = dom_order(&id, TRIGGERS).await;
+        let order: Vec<String> = {
+            let state = state.peek();
+            order.iter()
+                .filter_map(|id| state.focus.keys().find(|value| state.trigger_id(value) == *id).map(String::from))
+                .collect()
+        };
+        if !state.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            state.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Contains the triggers that are aligned along the edge of the active content
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+#[component]
+pub fn TabsList(
+    /// Whether keyboard navigation wraps around from the last tab to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+
+    /// Remaining attributes to add to the list `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TabsState>>();
+
+    use_effect(use_reactive!(|r#loop| {
+        state.write().focus.looped = r#loop.unwrap_or(true);
+    }));
+
+    rsx! {
+        div {
+            role: "tablist",
+            aria_orientation: state.read().orientation,
+            "data-orientation": state.read().orientation,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// The button that activates its associated content
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"active"` | `"inactive"`
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus into the tab list, onto the active tab, or out of the tab list.
+/// - `<Space>`: [Manual] Activates the focused tab.
+/// - `<Enter>`: [Manual] Activates the focused tab.
+/// - `<ArrowDown>`: [Vertical] Focus the next tab and activate it when automatic.
+/// - `<ArrowUp>`: [Vertical] Focus the previous tab and activate it when automatic.
+/// - `<ArrowRight>`: [Horizontal] Focus the next tab and activate it when automatic.
+/// - `<ArrowLeft>`: [Horizontal] Focus the previous tab and activate it when automatic.
+/// - `<Home>`: Focus the first tab and activate it when automatic.
+/// - `<End>`: Focus the last tab and activate it when automatic.
+#[component]
+pub fn TabsTrigger(
+    /// The unique value that associates the trigger with its content
+    #[props(into)]
+    value: String,
+    /// Whether the tab is disabled
+    ///
+    /// This means that it cannot be activated or focused with the arrow keys
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the trigger; `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TabsState>>();
+    let value = use_signal(|| value);
+    let disabled = disabled.unwrap_or_default();
+
+    use_effect(use_reactive!(|disabled| {
+        state.write().focus.set_disabled(value.peek().as_str(), disabled);
+    }));
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.focus.remove_item(value.peek().as_str());
+        }
+    });
+
+    let active = state.read().is_active(value.read().as_str());
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "tab",
+            id: state.read().trigger_id(&value.read()),
+            aria_selected: active,
+            aria_controls: state.read().content_id(&value.read()),
+            tabindex: if state.read().is_tab_stop(&value.read()) { 0 } else { -1 },
+            disabled: disabled,
+
+            "data-meek-tabs-trigger": true,
+            "data-state": if active { "active" } else { "inactive" },
+            "data-orientation": state.read().orientation,
+            "data-disabled": disabled,
+
+            onclick: move |_| if !disabled { state.write().select(value.read().as_str()) },
+            onfocus: move |_| {
+                // Automatic activation also activates tabs reached through keyboard navigation
+                if !disabled && state.read().activation_mode.is_automatic() {
+                    state.write().select(value.read().as_str());
+                }
+            },
+            onkeydown: move |evt| async move {
+                let target = state.read().focus.navigate(value.read().as_str(), &evt);
+                if let Some((_, data)) = target {
+                    // TODO: Handle set_focus result response
+                    let _ = data.set_focus(true).await;
+                }
+            },
+
+            onmounted: move |v: Event<MountedData>| {
+                state.write().focus.add_item(value.read().as_str(), v.data());
+                schedule_order(state);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Contains the content associated with each trigger
+///
+/// The content stays mounted until its exit animations have finished after the tab is deactivated.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"active"` | `"inactive"`
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+#[component]
+pub fn TabsContent(
+    /// The unique value that associates the content with its trigger
+    #[props(into)]
+    value: String,
+    /// Keep the content mounted even when the tab is inactive
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<TabsState>>();
+
+    let content_id = use_memo(use_reactive!(|value| state.read().content_id(&value)));
+    let active = use_memo(use_reactive!(|value| state.read().is_active(&value)));
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || active()));
+    let is_present = use_presence(content_id, present);
+
+    rsx! {
+        div {
+            role: "tabpanel",
+            id: content_id,
+            aria_labelledby: state.read().trigger_id(&value),
+            tabindex: 0,
+
+            hidden: !is_present(),
+
+            "data-state": if active() { "active" } else { "inactive" },
+            "data-orientation": state.read().orientation,
+
+            ..attrs,
+
+            if is_present() {
+                {children}
+            }
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::{create_id, dom_order, roving_focus::RovingFocus, Optional, OptionalMaybeSignal, Orientation};
+
+/// Selector matching the items of a toggle group
+const ITEMS: &str = "[data-meek-toggle-group-item]";
+
+/// Whether one or many items of a toggle group can be pressed
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum ToggleGroupType {
+    #[default]
+    Single,
+    Multiple,
+}
+
+impl<A: AsRef<str>> From<A> for ToggleGroupType {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "single" => Self::Single,
+            "multiple" => Self::Multiple,
+            other => panic!("unknown toggle group type: '{other}'")
+        }
+    }
+}
+
+/// Handles the contextual state of a group of toggle buttons
+#[derive(Debug)]
+pub(crate) struct ToggleGroupState {
+    pub typ: ToggleGroupType,
+    pub disabled: bool,
+
+    current: Signal<Vec<String>>,
+    onchange: Option<EventHandler<Vec<String>>>,
+}
+
+impl ToggleGroupState {
+    pub fn new(
+        typ: ToggleGroupType,
+        disabled: Option<bool>,
+        current: Signal<Vec<String>>,
+        onchange: Option<EventHandler<Vec<String>>>,
+    ) -> Self {
+        Self {
+            typ,
+            disabled: disabled.unwrap_or_default(),
+            current,
+            onchange,
+        }
+    }
+
+    /// Limit the pressed items based on the group type
+    pub fn limit(typ: ToggleGroupType, values: Vec<String>) -> Vec<String> {
+        if typ.is_multiple() {
+            values
+        } else {
+            values.into_iter().take(1).collect()
+        }
+    }
+
+    /// Replace the pressed items without notifying `onchange`
+    pub fn sync(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.current.peek() != values {
+            self.current.set(values);
+        }
+    }
+
+    /// Check if an item is pressed
+    pub fn is_pressed(&self, value: impl AsRef<str>) -> bool {
+        self.current.read().iter().any(|current| current == value.as_ref())
+    }
+
+    /// Press or release an item
+    ///
+    /// Pressing an item of a single group releases the item that was pressed before
+    pub fn toggle(&mut self, value: impl AsRef<str>) {
+        let value = value.as_ref();
+        let values = if self.is_pressed(value) {
+            self.current.peek().iter().filter(|current| *current != value).cloned().collect()
+        } else if self.typ.is_single() {
+            vec![value.to_string()]
+        } else {
+            let mut values = self.current.peek().clone();
+            values.push(value.to_string());
+            values
+        };
+
+        self.current.set(values);
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(self.current.peek().clone());
+        }
+    }
+
+    /// Role of the group, single groups behave like radio groups
+    pub fn role(&self) -> &'static str {
+        if self.typ.is_single() { "radiogroup" } else { "group" }
+    }
+}
+
+/// Handles the roving focus of the items in a `ToggleGroup`
+#[derive(Debug)]
+struct ToggleGroupFocus {
+    id: String,
+    /// `None` when the arrow keys don't move focus between the items
+    roving: Option<RovingFocus>,
+    /// Item that had focus last
+    focused: Option<String>,
+    /// Values of the items by the id of their element
+    ids: HashMap<String, String>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+}
+
+impl ToggleGroupFocus {
+    /// Tab index of the item, `None` when the group doesn't move focus between its items
+    fn tabindex(&self, group: &ToggleGroupState, value: &str) -> Option<i64> {
+        let focus = self.roving.as_ref()?;
+        let tab_stop = match self.focused.as_deref().filter(|focused| focus.position(focused).is_some()) {
+            Some(focused) => focused == value,
+            None => match focus.keys().find(|key| group.is_pressed(key)) {
+                Some(pressed) => pressed == value,
+                None => focus.first() == Some(value),
+            },
+        };
+        Some(if tab_stop { 0 } else { -1 })
+    }
+
+    /// Move the focus state of an item over to its new value
+    fn rename(&mut self, from: &str, to: &str) {
+        if let Some(roving) = self.roving.as_mut() {
+            roving.rename_item(from, to);
+        }
+        if self.focused.as_deref() == Some(from) {
+            self.focused = Some(to.to_string());
+        }
+    }
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut focus: Signal<ToggleGroupFocus>) {
+    if std::mem::replace(&mut focus.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut focus = focus.write();
+            focus.ordering = false;
+            focus.id.clone()
+        };
+        let order = dom_order(&id, ITEMS).await;
+        let order: Vec<String> = {
+            let focus = focus.peek();
+            order.iter().filter_map(|id| focus.ids.get(id).cloned()).collect()
+        };
+        let ordered = focus.peek().roving.as_ref().is_none_or(|roving| roving.keys().eq(order.iter().map(String::as_str)));
+        if !ordered {
+            if let Some(roving) = focus.write().roving.as_mut() {
+                roving.reorder(&order);
+            }
+        }
+    });
+}
+
+/// Toggle Group
+///
+/// A set of two-state buttons that can be toggled on or off.
+///
+/// Single groups behave like a radio group, where pressing an item releases the other one.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the item that had focus last, the first pressed item or the first item.
+/// - `<Space>`: Toggles the focused item.
+/// - `<Enter>`: Toggles the focused item.
+/// - `<ArrowDown>`: [Vertical] Moves focus to the next item.
+/// - `<ArrowUp>`: [Vertical] Moves focus to the previous item.
+/// - `<ArrowRight>`: [Horizontal] Moves focus to the next item.
+/// - `<ArrowLeft>`: [Horizontal] Moves focus to the previous item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last item.
+#[component]
+pub fn ToggleGroup(
+    /// Pressed item(s)
+    ///
+    /// When a signal is passed it is kept in sync with the pressed items
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default item(s) to press
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the pressed items change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether one or many items can be pressed
+    #[props(into, default)]
+    r#type: ToggleGroupType,
+    /// Whether every item in the group is disabled
+    disabled: Option<bool>,
+    /// Direction of the group. When not set, both the vertical and horizontal arrow keys move focus
+    orientation: Option<Orientation>,
+    /// Whether keyboard navigation wraps around from the last item to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+    /// Whether the arrow keys move focus between the items, making the group a single tab stop. Defaults to `true`
+    roving_focus: Option<bool>,
+    /// User defined ID of the toggle group
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let current = value.clone().as_signal(ToggleGroupState::limit(r#type, default.unwrap_or_default()));
+    let mut state = use_context_provider(|| Signal::new(ToggleGroupState::new(r#type, disabled, current, onchange)));
+    let mut focus = use_context_provider(|| Signal::new(ToggleGroupFocus {
+        id: id.unwrap_or(create_id()),
+        roving: roving_focus.unwrap_or(true).then(|| RovingFocus::new(orientation, r#loop.unwrap_or(true))),
+        focused: None,
+        ids: HashMap::new(),
+        ordering: false,
+    }));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|disabled, orientation, r#loop| {
+        state.write().disabled = disabled.unwrap_or_default();
+        if let Some(roving) = focus.write().roving.as_mut() {
+            roving.orientation = orientation;
+            roving.looped = r#loop.unwrap_or(true);
+        }
+    }));
+
+    let group = state.read();
+
+    rsx! {
+        div {
+            role: group.role(),
+            id: focus.read().id.as_str(),
+            aria_orientation: orientation,
+            "data-orientation": orientation,
+            "data-disabled": group.disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A toggle button in a `ToggleGroup`
+///
+/// The group decides whether the item is pressed. It renders its own `button` rather than a toggled `Button`, as the
+/// items of a single group are radios that report `aria-checked` instead of `aria-pressed`, and the roving focus
+/// needs the mounted data of the item, which `Button` doesn't expose.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"on"` | `"off"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ToggleGroupItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` event is run
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    ///
+    /// This means that it cannot be pressed or focused with the arrow keys
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the item `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<ToggleGroupState>>();
+    let mut focus = use_context::<Signal<ToggleGroupFocus>>();
+    let disabled = state.read().disabled || disabled.unwrap_or_default();
+    let id = use_hook(create_id);
+    let value = use_memo(use_reactive!(|value| value));
+    let mut registered = use_signal(|| None::<String>);
+
+    use_effect({
+        let id = id.clone();
+        move || {
+            let value = value();
+            let mut focus = focus.write();
+            if let Some(previous) = registered.peek().clone().filter(|previous| *previous != value) {
+                focus.rename(&previous, &value);
+            }
+            focus.ids.insert(id.clone(), value.clone());
+            registered.set(Some(value));
+        }
+    });
+
+    use_effect(use_reactive!(|disabled| {
+        if let Some(roving) = focus.write().roving.as_mut() {
+            roving.set_disabled(value.peek().as_str(), disabled);
+        }
+    }));
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut focus) = focus.try_write() {
+                if let Some(roving) = focus.roving.as_mut() {
+                    roving.remove_item(value.peek().as_str());
+                }
+                focus.ids.remove(&id);
+            }
+        }
+    });
+
+    let group = state.read();
+    let single = group.typ.is_single();
+    let pressed = group.is_pressed(value.read().as_str());
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: id.as_str(),
+            role: if single { Some("radio") } else { None },
+            aria_checked: if single { Some(pressed) } else { None },
+            aria_pressed: if single { None } else { Some(pressed) },
+            tabindex: focus.read().tabindex(&group, &value.read()),
+            disabled: disabled,
+
+            "data-meek-toggle-group-item": true,
+            "data-state": if pressed { "on" } else { "off" },
+            "data-disabled": disabled,
+
+            onclick: move |_| state.write().toggle(value.read().as_str()),
+            onfocus: move |_| focus.write().focused = Some(value()),
+            onkeydown: move |evt| async move {
+                let target = focus.read().roving.as_ref().and_then(|roving| roving.navigate(value.read().as_str(), &evt));
+                if let Some((_, data)) = target {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onmounted: move |v: Event<MountedData>| {
+                if let Some(roving) = focus.write().roving.as_mut() {
+                    roving.add_item(value.read().as_str(), v.data());
+                }
+                schedule_order(focus);
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
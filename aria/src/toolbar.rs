@@ -0,0 +1,393 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dom_order,
+    roving_focus::RovingFocus,
+    toggle_group::{ToggleGroupState, ToggleGroupType},
+    Optional,
+    OptionalMaybeSignal,
+    Orientation,
+};
+
+/// Selector matching the controls of a toolbar that receive focus
+const ITEMS: &str = "[data-meek-toolbar-item]";
+
+/// Handles the contextual state of a toolbar
+#[derive(Debug)]
+struct ToolbarState {
+    id: String,
+    pub orientation: Orientation,
+
+    focus: RovingFocus,
+    /// Item that had focus last
+    focused: Option<String>,
+    /// Whether the items are about to be sorted in the order they are rendered
+    ordering: bool,
+}
+
+impl ToolbarState {
+    /// Whether the item is the one that is reached when tabbing into the toolbar
+    fn is_tab_stop(&self, key: &str) -> bool {
+        match self.focused.as_deref().filter(|focused| self.focus.position(focused).is_some()) {
+            Some(focused) => focused == key,
+            None => self.focus.first() == Some(key),
+        }
+    }
+}
+
+/// Register an item with the toolbar's roving focus
+///
+/// Returns the toolbar state and the key the item is registered with.
+fn use_toolbar_item(disabled: bool) -> (Signal<ToolbarState>, Signal<String>) {
+    let mut state = use_context::<Signal<ToolbarState>>();
+    let key = use_signal(create_id);
+
+    use_effect(use_reactive!(|disabled| {
+        state.write().focus.set_disabled(key.peek().as_str(), disabled);
+    }));
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.focus.remove_item(key.peek().as_str());
+        }
+    });
+
+    (state, key)
+}
+
+/// Register the mounted item with the toolbar's roving focus
+fn mount_toolbar_item(mut state: Signal<ToolbarState>, key: Signal<String>, data: Rc<MountedData>) {
+    state.write().focus.add_item(key.read().as_str(), data);
+    schedule_order(state);
+}
+
+/// Sort the items in the order they are rendered, which can differ from the order they mounted in
+///
+/// Items that mount in the same render are sorted together once the DOM has been updated.
+fn schedule_order(mut state: Signal<ToolbarState>) {
+    if std::mem::replace(&mut state.write().ordering, true) {
+        return;
+    }
+
+    spawn(async move {
+        let id = {
+            let mut state = state.write();
+            state.ordering = false;
+            state.id.clone()
+        };
+        let order = dom_order(&id, ITEMS).await;
+        let order: Vec<String> = {
+            let state = state.peek();
+            order.into_iter().filter(|id| state.focus.position(id).is_some()).collect()
+        };
+        if !state.peek().focus.keys().eq(order.iter().map(String::as_str)) {
+            state.write().focus.reorder(&order);
+        }
+    });
+}
+
+/// Toolbar
+///
+/// A container for grouping a set of controls, such as buttons, links and toggle groups.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the control that had focus last, or the first control.
+/// - `<ArrowRight>`: [Horizontal] Moves focus to the next control.
+/// - `<ArrowLeft>`: [Horizontal] Moves focus to the previous control.
+/// - `<ArrowDown>`: [Vertical] Moves focus to the next control.
+/// - `<ArrowUp>`: [Vertical] Moves focus to the previous control.
+/// - `<Home>`: Moves focus to the first control.
+/// - `<End>`: Moves focus to the last control.
+#[component]
+pub fn Toolbar(
+    /// Direction of the toolbar. Defaults to `horizontal`
+    orientation: Option<Orientation>,
+    /// Whether keyboard navigation wraps around from the last control to the first and vice versa. Defaults to `true`
+    r#loop: Option<bool>,
+    /// User defined ID of the toolbar
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the toolbar `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let orientation = orientation.unwrap_or(Orientation::Horizontal);
+    let mut state = use_context_provider(|| Signal::new(ToolbarState {
+        id: id.unwrap_or(create_id()),
+        orientation,
+        focus: RovingFocus::new(Some(orientation), r#loop.unwrap_or(true)),
+        focused: None,
+        ordering: false,
+    }));
+
+    use_effect(use_reactive!(|orientation, r#loop| {
+        let mut state = state.write();
+        state.orientation = orientation;
+        state.focus.orientation = Some(orientation);
+        state.focus.looped = r#loop.unwrap_or(true);
+    }));
+
+    rsx! {
+        div {
+            role: "toolbar",
+            id: state.read().id.as_str(),
+            aria_orientation: orientation,
+            "data-orientation": orientation,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A button in the toolbar
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ToolbarButton(
+    /// Whether the button is disabled
+    ///
+    /// This means that it cannot be clicked or focused with the arrow keys
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the button `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let disabled = disabled.unwrap_or_default();
+    let (mut state, key) = use_toolbar_item(disabled);
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: key,
+            tabindex: if state.read().is_tab_stop(&key.read()) { 0 } else { -1 },
+            disabled: disabled,
+
+            "data-meek-toolbar-item": true,
+            "data-orientation": state.read().orientation,
+            "data-disabled": disabled,
+
+            onfocus: move |_| state.write().focused = Some(key()),
+            onkeydown: move |evt| async move {
+                let target = state.read().focus.navigate(key.read().as_str(), &evt);
+                if let Some((_, data)) = target {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onmounted: move |v: Event<MountedData>| mount_toolbar_item(state, key, v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A link in the toolbar
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+#[component]
+pub fn ToolbarLink(
+    /// Remaining attributes to add to the link `a` tag
+    #[props(extends = GlobalAttributes, extends = a)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let (mut state, key) = use_toolbar_item(false);
+
+    rsx! {
+        a {
+            id: key,
+            tabindex: if state.read().is_tab_stop(&key.read()) { 0 } else { -1 },
+
+            "data-meek-toolbar-item": true,
+            "data-orientation": state.read().orientation,
+
+            onfocus: move |_| state.write().focused = Some(key()),
+            onkeydown: move |evt| async move {
+                let target = state.read().focus.navigate(key.read().as_str(), &evt);
+                if let Some((_, data)) = target {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onmounted: move |v: Event<MountedData>| mount_toolbar_item(state, key, v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Visually or semantically separates controls in the toolbar
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`, perpendicular to the toolbar
+#[component]
+pub fn ToolbarSeparator(
+    /// Remaining attributes to add to the separator `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+) -> Element {
+    let state = use_context::<Signal<ToolbarState>>();
+    let orientation = match state.read().orientation {
+        Orientation::Horizontal => Orientation::Vertical,
+        Orientation::Vertical => Orientation::Horizontal,
+    };
+
+    rsx! {
+        div {
+            role: "separator",
+            aria_orientation: orientation,
+            "data-orientation": orientation,
+
+            ..attrs,
+        }
+    }
+}
+
+/// A group of toggle buttons in the toolbar
+///
+/// The buttons are part of the toolbar's keyboard navigation.
+///
+/// # Data Attributes
+///
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ToolbarToggleGroup(
+    /// Pressed item(s)
+    ///
+    /// When a signal is passed it is kept in sync with the pressed items
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default item(s) to press
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the pressed items change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether one or many items can be pressed
+    #[props(into, default)]
+    r#type: ToggleGroupType,
+    /// Whether every item in the group is disabled
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let toolbar = use_context::<Signal<ToolbarState>>();
+    let current = value.clone().as_signal(ToggleGroupState::limit(r#type, default.unwrap_or_default()));
+    let mut state = use_context_provider(|| Signal::new(ToggleGroupState::new(r#type, disabled, current, onchange)));
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|disabled| state.write().disabled = disabled.unwrap_or_default()));
+
+    let group = state.read();
+
+    rsx! {
+        div {
+            role: group.role(),
+            "data-orientation": toolbar.read().orientation,
+            "data-disabled": group.disabled,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// A toggle button in a `ToolbarToggleGroup`
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"on"` | `"off"`
+/// - `[data-orientation]`: `"vertical"` | `"horizontal"`
+/// - `[data-disabled]`: Present when disabled
+#[component]
+pub fn ToolbarToggleItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` event is run
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+
+    /// Remaining attributes to add to the item `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut group = use_context::<Signal<ToggleGroupState>>();
+    let disabled = group.read().disabled || disabled.unwrap_or_default();
+    let (mut state, key) = use_toolbar_item(disabled);
+    let value = use_memo(use_reactive!(|value| value));
+
+    let single = group.read().typ.is_single();
+    let pressed = group.read().is_pressed(value.read().as_str());
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: key,
+            role: if single { Some("radio") } else { None },
+            aria_checked: if single { Some(pressed) } else { None },
+            aria_pressed: if single { None } else { Some(pressed) },
+            tabindex: if state.read().is_tab_stop(&key.read()) { 0 } else { -1 },
+            disabled: disabled,
+
+            "data-meek-toolbar-item": true,
+            "data-state": if pressed { "on" } else { "off" },
+            "data-orientation": state.read().orientation,
+            "data-disabled": disabled,
+
+            onclick: move |_| group.write().toggle(value.read().as_str()),
+            onfocus: move |_| state.write().focused = Some(key()),
+            onkeydown: move |evt| async move {
+                let target = state.read().focus.navigate(key.read().as_str(), &evt);
+                if let Some((_, data)) = target {
+                    let _ = data.set_focus(true).await;
+                }
+            },
+            onmounted: move |v: Event<MountedData>| mount_toolbar_item(state, key, v.data()),
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
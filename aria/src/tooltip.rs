@@ -0,0 +1,531 @@
+use dioxus::prelude::*;
+
+use crate::{
+    create_id,
+    dismissable_layer::{use_dismissable_layer, DismissableLayerHandlers},
+    popper::{render_arrow, use_popper, Placement, PopperAnchor, PopperContext, PopperOptions},
+    presence::use_presence,
+    sleep,
+    Align,
+    DismissableLayerEvent,
+    Provider,
+    Side,
+    Teleport,
+};
+
+/// How long the pointer can be outside of both the trigger and hoverable content before the tooltip closes
+const HOVER_GRACE_DURATION: u64 = 150;
+
+/// Delays that are shared by every tooltip inside of a `TooltipProvider`
+#[derive(Debug)]
+struct TooltipProviderState {
+    pub delay_duration: u64,
+    pub skip_delay_duration: u64,
+    pub disable_hoverable_content: bool,
+
+    /// Whether the next tooltip waits for `delay_duration` before opening
+    open_delayed: bool,
+    /// Id of the tooltip that is currently open
+    current: Option<String>,
+    skip_timer: Option<Task>,
+}
+
+impl TooltipProviderState {
+    fn new(delay_duration: Option<u64>, skip_delay_duration: Option<u64>, disable_hoverable_content: Option<bool>) -> Self {
+        Self {
+            delay_duration: delay_duration.unwrap_or(700),
+            skip_delay_duration: skip_delay_duration.unwrap_or(300),
+            disable_hoverable_content: disable_hoverable_content.unwrap_or_default(),
+            open_delayed: true,
+            current: None,
+            skip_timer: None,
+        }
+    }
+}
+
+/// Sets the delays of every tooltip rendered inside of it
+///
+/// Render it once near the root of the app. Tooltips that are not inside of a provider use the default delays.
+#[component]
+pub fn TooltipProvider(
+    /// Milliseconds from when the pointer enters a trigger until the tooltip opens. Defaults to `700`
+    delay_duration: Option<u64>,
+    /// Milliseconds after a tooltip closes during which another tooltip opens without a delay. Defaults to `300`
+    skip_delay_duration: Option<u64>,
+    /// Close tooltips as soon as the pointer leaves the trigger, even when moving onto the content. Defaults to `false`
+    disable_hoverable_content: Option<bool>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context_provider(|| Signal::new(
+        TooltipProviderState::new(delay_duration, skip_delay_duration, disable_hoverable_content)
+    ));
+
+    use_effect(use_reactive!(|delay_duration, skip_delay_duration, disable_hoverable_content| {
+        let mut state = state.write();
+        state.delay_duration = delay_duration.unwrap_or(700);
+        state.skip_delay_duration = skip_delay_duration.unwrap_or(300);
+        state.disable_hoverable_content = disable_hoverable_content.unwrap_or_default();
+    }));
+
+    rsx! {
+        {children}
+    }
+}
+
+/// Handles the contextual state of a tooltip
+#[derive(Debug)]
+struct TooltipState {
+    id: String,
+    pub delay_duration: Option<u64>,
+    pub disable_hoverable_content: Option<bool>,
+
+    provider: Signal<TooltipProviderState>,
+    /// Pending open or close of the tooltip
+    timer: Option<Task>,
+    /// Whether the trigger is being pressed, focus caused by pressing it does not open the tooltip
+    pressed: bool,
+
+    open: Signal<bool>,
+    onchange: Option<EventHandler<bool>>,
+}
+
+impl TooltipState {
+    /// Get the formatted trigger id
+    fn trigger_id(&self) -> String {
+        format!("{}:trigger", self.id)
+    }
+
+    /// Get the formatted id of the element that positions the content
+    fn popper_id(&self) -> String {
+        format!("{}:popper", self.id)
+    }
+
+    fn is_hoverable(&self) -> bool {
+        !self.disable_hoverable_content.unwrap_or(self.provider.peek().disable_hoverable_content)
+    }
+
+    fn cancel_timer(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.cancel();
+        }
+    }
+
+    fn set_open(&mut self, open: bool) {
+        self.cancel_timer();
+        if *self.open.peek() == open {
+            return;
+        }
+
+        self.open.set(open);
+        if let Some(onchange) = self.onchange.as_ref() {
+            onchange.call(open);
+        }
+
+        let mut handle = self.provider;
+        let mut provider = self.provider.write();
+        if let Some(timer) = provider.skip_timer.take() {
+            timer.cancel();
+        }
+        if open {
+            provider.open_delayed = false;
+            provider.current = Some(self.id.clone());
+        } else {
+            if provider.current.as_deref() == Some(self.id.as_str()) {
+                provider.current = None;
+            }
+
+            // Tooltips that open shortly after this one closed skip the delay
+            let skip = provider.skip_delay_duration;
+            provider.skip_timer = Some(spawn(async move {
+                sleep(skip).await;
+                let mut provider = handle.write();
+                provider.open_delayed = true;
+                provider.skip_timer = None;
+            }));
+        }
+    }
+}
+
+/// Open or close the tooltip after waiting for `delay` milliseconds
+fn schedule(mut state: Signal<TooltipState>, open: bool, delay: u64) {
+    let mut handle = state;
+    let mut current = state.write();
+    current.cancel_timer();
+    if delay == 0 {
+        current.set_open(open);
+        return;
+    }
+
+    current.timer = Some(spawn(async move {
+        sleep(delay).await;
+        let mut state = handle.write();
+        state.timer = None;
+        state.set_open(open);
+    }));
+}
+
+/// Tooltip
+///
+/// A popup that displays information related to an element when the element receives keyboard focus or the
+/// pointer hovers over it.
+#[component]
+pub fn Tooltip(
+    /// Passed state whether the tooltip should be open or closed
+    open: Option<bool>,
+    /// Default open state of the tooltip
+    default: Option<bool>,
+    /// Event handler for when the open state changes
+    onchange: Option<EventHandler<bool>>,
+
+    /// Overrides the `delay_duration` of the `TooltipProvider`
+    delay_duration: Option<u64>,
+    /// Overrides the `disable_hoverable_content` of the `TooltipProvider`
+    disable_hoverable_content: Option<bool>,
+
+    /// User defined ID of the tooltip content
+    #[props(into)]
+    id: Option<String>,
+
+    children: Element,
+) -> Element {
+    let provider = use_hook(|| {
+        try_consume_context::<Signal<TooltipProviderState>>()
+            .unwrap_or_else(|| Signal::new(TooltipProviderState::new(None, None, None)))
+    });
+
+    let mut current = use_signal(|| open.or(default).unwrap_or_default());
+    let mut state = use_context_provider(|| Signal::new(TooltipState {
+        id: id.unwrap_or(create_id()),
+        delay_duration,
+        disable_hoverable_content,
+        provider,
+        timer: None,
+        pressed: false,
+        open: current,
+        onchange,
+    }));
+
+    use_effect(use_reactive!(|open| {
+        if let Some(open) = open {
+            current.set(open);
+        }
+    }));
+
+    use_effect(use_reactive!(|delay_duration, disable_hoverable_content| {
+        let mut state = state.write();
+        state.delay_duration = delay_duration;
+        state.disable_hoverable_content = disable_hoverable_content;
+    }));
+
+    // Only one tooltip is open at a time
+    use_effect(move || {
+        let other = provider.read().current.clone();
+        let id = state.peek().id.clone();
+        if other.is_some_and(|other| other != id) && *current.peek() {
+            state.write().set_open(false);
+        }
+    });
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.cancel_timer();
+        }
+    });
+
+    rsx! {
+        {children}
+    }
+}
+
+/// The button that opens the tooltip when it is hovered or focused
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"closed"` | `"delayed-open"` | `"instant-open"`
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Opens the tooltip without a delay when the trigger receives focus.
+/// - `<Space>`: Closes the tooltip and activates the trigger.
+/// - `<Enter>`: Closes the tooltip and activates the trigger.
+/// - `<Escape>`: Closes the tooltip.
+#[component]
+pub fn TooltipTrigger(
+    /// Whether the trigger button is disabled
+    disabled: Option<bool>,
+    /// Handler that is run when the trigger is clicked, after the tooltip is closed
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// Remaining attributes to add to the trigger `button` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TooltipState>>();
+    let open = *state.read().open.read();
+    let delayed = use_signal(|| false);
+
+    let open_state = match (open, delayed()) {
+        (false, _) => "closed",
+        (true, true) => "delayed-open",
+        (true, false) => "instant-open",
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: state.read().trigger_id(),
+            disabled: disabled,
+            aria_disabled: disabled,
+            aria_describedby: if open { Some(state.read().id.clone()) } else { None },
+
+            "data-state": open_state,
+
+            onpointerenter: move |evt: Event<PointerData>| {
+                if evt.pointer_type() == "touch" {
+                    return;
+                }
+                let mut delayed = delayed;
+                let delay = {
+                    let state = state.read();
+                    let provider = state.provider.peek();
+                    if provider.open_delayed {
+                        state.delay_duration.unwrap_or(provider.delay_duration)
+                    } else {
+                        0
+                    }
+                };
+                delayed.set(delay > 0);
+                schedule(state, true, delay);
+            },
+            onpointerleave: move |_| {
+                state.write().pressed = false;
+                let grace = if state.read().is_hoverable() { HOVER_GRACE_DURATION } else { 0 };
+                schedule(state, false, grace);
+            },
+            onpointerdown: move |_| {
+                let mut state = state.write();
+                state.pressed = true;
+                state.set_open(false);
+            },
+            onfocus: move |_| {
+                let mut delayed = delayed;
+                let mut state = state.write();
+                if std::mem::take(&mut state.pressed) {
+                    return;
+                }
+                delayed.set(false);
+                state.set_open(true);
+            },
+            onblur: move |_| state.write().set_open(false),
+            onclick: move |evt| {
+                state.write().set_open(false);
+                if let Some(onclick) = onclick.as_ref() {
+                    onclick.call(evt);
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Teleports the content to where the `Portal` with the same `name` is rendered
+///
+/// Use this to escape `overflow: hidden` and stacking contexts of the parent elements.
+#[component]
+pub fn TooltipPortal(
+    /// Name of the `Portal` to render the content in
+    #[props(into)]
+    name: Option<String>,
+
+    children: Element,
+) -> Element {
+    let state = use_context::<Signal<TooltipState>>();
+
+    rsx! {
+        Teleport {
+            name: name,
+            // Teleported elements lose their context, so it is provided again where they are rendered
+            Provider {
+                inherit: state,
+                {children}
+            }
+        }
+    }
+}
+
+/// The component that pops out when the tooltip is open
+///
+/// The content stays open while the pointer is over it, unless hoverable content is disabled.
+/// It stays mounted until its exit animations have finished after it is closed.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"closed"` | `"open"`
+/// - `[data-side]`: `"top"` | `"right"` | `"bottom"` | `"left"`
+/// - `[data-align]`: `"start"` | `"center"` | `"end"`
+///
+/// # CSS Variables
+///
+/// - `--meek-popper-available-width`: The width between the anchor and the edge of the boundary
+/// - `--meek-popper-available-height`: The height between the anchor and the edge of the boundary
+/// - `--meek-popper-anchor-width`: The width of the trigger
+/// - `--meek-popper-anchor-height`: The height of the trigger
+/// - `--meek-popper-transform-origin`: The origin of the content's animations, computed from the trigger and arrow
+#[component]
+pub fn TooltipContent(
+    /// The side of the trigger to place the content against. Defaults to `top`
+    #[props(into, default = Side::Top)]
+    side: Side,
+    /// Distance in pixels between the trigger and the content. Defaults to `0`
+    side_offset: Option<f64>,
+    /// Alignment of the content along the side of the trigger. Defaults to `center`
+    #[props(into, default)]
+    align: Align,
+    /// Offset in pixels from the `align` position. Defaults to `0`
+    align_offset: Option<f64>,
+    /// Whether the content flips to the opposite side and shifts along the trigger to stay inside of the boundary.
+    /// Defaults to `true`
+    avoid_collisions: Option<bool>,
+    /// Distance in pixels from the edges of the boundary where collisions are detected. Defaults to `0`
+    collision_padding: Option<f64>,
+    /// Id of the element that is used as the collision boundary. Defaults to the viewport
+    #[props(into)]
+    collision_boundary: Option<String>,
+    /// Distance in pixels between the arrow and the edges of the content. Defaults to `0`
+    arrow_padding: Option<f64>,
+
+    /// Keep the content mounted even when the tooltip is closed
+    ///
+    /// Useful when controlling animations with a third party library
+    force_mount: Option<bool>,
+
+    /// Handler that is run when the `<Escape>` key is pressed
+    ///
+    /// Call `prevent_default` on the event to keep the tooltip open
+    onescapekeydown: Option<EventHandler<DismissableLayerEvent>>,
+    /// Handler that is run when a pointer is pressed outside of the content
+    ///
+    /// Call `prevent_default` on the event to keep the tooltip open
+    onpointerdownoutside: Option<EventHandler<DismissableLayerEvent>>,
+
+    /// Remaining attributes to add to the content `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TooltipState>>();
+
+    let id = use_memo(move || state.read().id.clone());
+    let open = use_memo(move || *state.read().open.read());
+    let present = use_memo(use_reactive!(|force_mount| force_mount.unwrap_or_default() || open()));
+    let is_present = use_presence(id, present);
+
+    // The content may be teleported, so wait until it is in the DOM before managing it
+    let mut mounted = use_signal(|| false);
+    use_effect(move || if !is_present() { mounted.set(false) });
+    let active = use_memo(move || open() && mounted());
+
+    // Moving focus away is handled by the trigger losing focus
+    let onfocusoutside = use_callback(|evt: DismissableLayerEvent| evt.prevent_default());
+    let ondismiss = use_callback(move |_| state.write().set_open(false));
+    use_dismissable_layer(
+        state.peek().id.clone(),
+        active,
+        vec![state.peek().trigger_id()],
+        DismissableLayerHandlers {
+            onescapekeydown,
+            onpointerdownoutside,
+            onfocusoutside: Some(onfocusoutside),
+            ondismiss: Some(ondismiss),
+            ..Default::default()
+        },
+    );
+
+    let options = use_memo(use_reactive!(|side, side_offset, align, align_offset, avoid_collisions, collision_padding, collision_boundary, arrow_padding| {
+        PopperOptions {
+            side,
+            align,
+            side_offset: side_offset.unwrap_or_default(),
+            align_offset: align_offset.unwrap_or_default(),
+            avoid_collisions: avoid_collisions.unwrap_or(true),
+            collision_padding: collision_padding.unwrap_or_default(),
+            collision_boundary,
+            arrow_padding: arrow_padding.unwrap_or_default(),
+        }
+    }));
+    let anchor = use_memo(move || PopperAnchor::Element(state.read().trigger_id()));
+    let popper = use_memo(move || state.read().popper_id());
+    let tracking = use_memo(move || is_present() && mounted());
+    let placement = use_popper(anchor, popper, tracking, options);
+    use_context_provider(|| PopperContext { placement });
+
+    let placed = *placement.read();
+    let mut attrs = attrs;
+    Placement::push_attributes(placed.as_ref(), &mut attrs);
+
+    rsx! {
+        if is_present() {
+            div {
+                id: state.read().popper_id(),
+                position: "fixed",
+                left: "0px",
+                top: "0px",
+                min_width: "max-content",
+                transform: Placement::transform(placed.as_ref()),
+
+                div {
+                    role: "tooltip",
+                    id: state.read().id.as_str(),
+                    "data-state": if open() { "open" } else { "closed" },
+                    "data-side": placed.map(|p| p.side).unwrap_or(side),
+                    "data-align": placed.map(|p| p.align).unwrap_or(align),
+
+                    onmounted: move |_| mounted.set(true),
+                    onpointerenter: move |_| {
+                        let mut state = state.write();
+                        if state.is_hoverable() {
+                            state.cancel_timer();
+                        }
+                    },
+                    onpointerleave: move |_| {
+                        if state.read().is_hoverable() {
+                            schedule(state, false, HOVER_GRACE_DURATION);
+                        }
+                    },
+
+                    ..attrs,
+
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/// An arrow that points from the content to the trigger. Must live inside `TooltipContent`
+///
+/// Renders a triangle `svg` unless children are passed.
+#[component]
+pub fn TooltipArrow(
+    /// Width of the arrow in pixels. Defaults to `10`
+    width: Option<f64>,
+    /// Height of the arrow in pixels. Defaults to `5`
+    height: Option<f64>,
+
+    /// Remaining attributes to add to the arrow `span` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    render_arrow(width.unwrap_or(10.0), height.unwrap_or(5.0), attrs, children)
+}
@@ -0,0 +1,540 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::{create_id, hierarchy::{use_hierarchy_item, Hierarchy}, typeahead::typeahead, Optional, OptionalMaybeSignal};
+
+/// Selector matching the items of a tree
+const ITEMS: &str = "[data-meek-tree-item]";
+/// Selector matching the items of a tree that can be focused
+const ITEM_SELECTOR: &str = "[data-meek-tree-item]:not([data-disabled])";
+
+/// Whether a tree selects one or many items
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum TreeType {
+    #[default]
+    Single,
+    Multiple,
+}
+
+impl<A: AsRef<str>> From<A> for TreeType {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "single" => Self::Single,
+            "multiple" => Self::Multiple,
+            other => panic!("unknown tree type: '{other}'")
+        }
+    }
+}
+
+/// Item to move focus to, relative to the focused item
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Next,
+    Previous,
+    First,
+    Last,
+    Parent,
+    Child,
+}
+
+impl Target {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Next => "next",
+            Self::Previous => "previous",
+            Self::First => "first",
+            Self::Last => "last",
+            Self::Parent => "parent",
+            Self::Child => "child",
+        }
+    }
+}
+
+/// Find the item of the tree that is rendered at the target position relative to the item `current`
+///
+/// Items inside of collapsed groups aren't rendered, so this follows what is visible. Returns the id of the item.
+async fn find_item(tree: &str, current: &str, target: Target) -> Option<String> {
+    let target = target.as_str();
+    let mut eval = document::eval(&format!(r#"
+        const tree = document.getElementById({tree:?});
+        const current = document.getElementById({current:?});
+        if (!tree || !current) {{
+            dioxus.send(null);
+            return;
+        }}
+
+        const items = [...tree.querySelectorAll(`{ITEM_SELECTOR}`)];
+        const index = items.indexOf(current);
+        const parent = current.parentElement.closest("[data-meek-tree-item]");
+        const target = {{
+            next: () => items[index + 1],
+            previous: () => items[index - 1],
+            first: () => items[0],
+            last: () => items[items.length - 1],
+            parent: () => parent && tree.contains(parent) && !parent.hasAttribute("data-disabled") ? parent : null,
+            child: () => items.find((item) => item !== current && current.contains(item)),
+        }}["{target}"]();
+        dioxus.send(target?.id ?? null);
+    "#));
+
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
+
+/// Handles the contextual state of a tree
+#[derive(Debug)]
+struct TreeState {
+    id: String,
+    pub typ: TreeType,
+    pub disabled: bool,
+
+    /// Id of the item that currently has focus
+    focused: Option<String>,
+    /// The rendered items and which of them are expanded
+    hierarchy: Signal<Hierarchy>,
+
+    selected: Signal<Vec<String>>,
+    onchange: Option<EventHandler<Vec<String>>>,
+}
+
+impl TreeState {
+    /// Limit the selected items based on the tree type
+    fn limit(typ: TreeType, values: Vec<String>) -> Vec<String> {
+        if typ.is_multiple() {
+            values
+        } else {
+            values.into_iter().take(1).collect()
+        }
+    }
+
+    /// Replace the selected items without notifying `onchange`
+    fn sync_selected(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.selected.peek() != values {
+            self.selected.set(values);
+        }
+    }
+
+    /// Check if an item is selected
+    fn is_selected(&self, value: impl AsRef<str>) -> bool {
+        self.selected.read().iter().any(|selected| selected == value.as_ref())
+    }
+
+    /// Replace the selection
+    fn set(&mut self, values: Vec<String>) {
+        let values = Self::limit(self.typ, values);
+        if *self.selected.peek() != values {
+            self.selected.set(values);
+            if let Some(onchange) = self.onchange.as_ref() {
+                onchange.call(self.selected.peek().clone());
+            }
+        }
+    }
+
+    /// Select a single item, or toggle it when multiple items can be selected
+    fn select(&mut self, value: &str) {
+        let values = if self.typ.is_single() {
+            vec![value.to_string()]
+        } else if self.is_selected(value) {
+            self.selected.peek().iter().filter(|selected| *selected != value).cloned().collect()
+        } else {
+            let mut values = self.selected.peek().clone();
+            values.push(value.to_string());
+            values
+        };
+        self.set(values);
+    }
+
+    /// Select every rendered item, or unselect them when they are all selected already
+    fn toggle_all(&mut self) {
+        let mut all = Vec::new();
+        self.hierarchy.read().rendered(None, &mut all);
+        if all.iter().all(|value| self.is_selected(value)) {
+            self.set(Vec::new());
+        } else {
+            self.set(all);
+        }
+    }
+
+    /// Whether the item is the one that is reached when tabbing into the tree
+    fn is_tab_stop(&self, id: &str, value: &str) -> bool {
+        if let Some(focused) = self.focused.as_deref() {
+            return focused == id;
+        }
+        let selected = self.selected.read();
+        let hierarchy = self.hierarchy.read();
+        match selected.iter().find(|selected| hierarchy.ids.values().any(|rendered| rendered == *selected)) {
+            Some(selected) => selected == value,
+            None => hierarchy.first().is_some_and(|first| first == value),
+        }
+    }
+}
+
+/// Tree
+///
+/// A hierarchical list of items where items with children can be expanded and collapsed.
+///
+/// # Data Attributes
+///
+/// - `[data-disabled]`: Present when disabled
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// - `<Tab>`: Moves focus to the first selected item, or the first item if none are selected.
+/// - `<ArrowDown>`: Moves focus to the next visible item.
+/// - `<ArrowUp>`: Moves focus to the previous visible item.
+/// - `<ArrowRight>`: Expands a collapsed item, or moves focus to the first child of an expanded item.
+/// - `<ArrowLeft>`: Collapses an expanded item, or moves focus to the parent of the item.
+/// - `<Home>`: Moves focus to the first item.
+/// - `<End>`: Moves focus to the last visible item.
+/// - `<Enter>` | `<Space>`: Selects the focused item, or toggles it when multiple items can be selected.
+/// - `<*>`: Expands every item at the same level as the focused item.
+/// - Typing a character moves focus to the next visible item whose text starts with the typed characters.
+///
+/// When multiple items can be selected:
+///
+/// - `<Shift+ArrowDown>`: Moves focus to the next item and toggles it.
+/// - `<Shift+ArrowUp>`: Moves focus to the previous item and toggles it.
+/// - `<Ctrl+A>`: Selects every visible item, or unselects them when they are all selected.
+#[component]
+pub fn Tree(
+    /// Selected item(s)
+    ///
+    /// When a signal is passed it is kept in sync with the selected items
+    #[props(into, default)]
+    value: OptionalMaybeSignal<Vec<String>>,
+    /// Default item(s) to select
+    #[props(into, default)]
+    default: Optional<Vec<String>>,
+    /// Handler that is run when the selected items change
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Whether one or many items can be selected
+    #[props(into, default)]
+    r#type: TreeType,
+    /// Expanded item(s)
+    ///
+    /// When a signal is passed it is kept in sync with the expanded items
+    #[props(into, default)]
+    expanded: OptionalMaybeSignal<HashSet<String>>,
+    /// Default item(s) to expand
+    #[props(into, default)]
+    default_expanded: Optional<Vec<String>>,
+    /// Handler that is run when items are expanded or collapsed
+    onexpandedchange: Option<EventHandler<HashSet<String>>>,
+    /// Whether every item in the tree is disabled
+    disabled: Option<bool>,
+    /// User defined ID of the tree
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the tree `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let selected = value.clone().as_signal(TreeState::limit(r#type, default.unwrap_or_default()));
+    let current = expanded.clone().as_signal(default_expanded.unwrap_or_default().into_iter().collect());
+    let mut state = use_context_provider(|| {
+        let id = id.unwrap_or(create_id());
+        Signal::new(TreeState {
+            hierarchy: Signal::new(Hierarchy::new(id.clone(), ITEMS, current, onexpandedchange)),
+            id,
+            typ: r#type,
+            disabled: disabled.unwrap_or_default(),
+            focused: None,
+            selected,
+            onchange,
+        })
+    });
+    let mut hierarchy = state.peek().hierarchy;
+
+    use_effect(use_reactive!(|value| {
+        if let OptionalMaybeSignal::Normal(value) = value {
+            state.write().sync_selected(value);
+        }
+    }));
+
+    use_effect(use_reactive!(|expanded| {
+        if let OptionalMaybeSignal::Normal(expanded) = expanded {
+            hierarchy.write().sync_expanded(expanded);
+        }
+    }));
+
+    use_effect(use_reactive!(|disabled| state.write().disabled = disabled.unwrap_or_default()));
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        let (id, focused, value, multiple) = {
+            let state = state.read();
+            let Some(focused) = state.focused.clone() else {
+                return;
+            };
+            let Some(value) = state.hierarchy.read().ids.get(&focused).cloned() else {
+                return;
+            };
+            if state.disabled {
+                return;
+            }
+            (state.id.clone(), focused, value, state.typ.is_multiple())
+        };
+
+        let key = evt.key();
+        let modifiers = evt.modifiers();
+        let ctrl = modifiers.ctrl() || modifiers.meta();
+        let target = match key {
+            Key::ArrowDown => Target::Next,
+            Key::ArrowUp => Target::Previous,
+            Key::Home => Target::First,
+            Key::End => Target::Last,
+            Key::ArrowRight => {
+                evt.prevent_default();
+                let mut hierarchy = hierarchy.write();
+                if !hierarchy.is_expandable(&value) {
+                    return;
+                }
+                if !hierarchy.is_expanded(&value) {
+                    hierarchy.set_expanded([&value], true);
+                    return;
+                }
+                Target::Child
+            }
+            Key::ArrowLeft => {
+                evt.prevent_default();
+                let mut hierarchy = hierarchy.write();
+                if hierarchy.is_expanded(&value) {
+                    hierarchy.set_expanded([&value], false);
+                    return;
+                }
+                Target::Parent
+            }
+            Key::Enter => {
+                evt.prevent_default();
+                state.write().select(&value);
+                return;
+            }
+            Key::Character(ref character) if character == " " => {
+                evt.prevent_default();
+                state.write().select(&value);
+                return;
+            }
+            Key::Character(ref character) if character == "*" => {
+                evt.prevent_default();
+                let siblings = hierarchy.peek().siblings(&value);
+                hierarchy.write().set_expanded(&siblings, true);
+                return;
+            }
+            Key::Character(ref character) if multiple && ctrl && character.eq_ignore_ascii_case("a") => {
+                evt.prevent_default();
+                state.write().toggle_all();
+                return;
+            }
+            Key::Character(ref character) if character.chars().count() == 1 && !ctrl && !modifiers.alt() => {
+                let Some(target) = typeahead(&id, ITEM_SELECTOR, character, Some(&focused)).await else {
+                    return;
+                };
+                let data = hierarchy.read().mounted.get(&target).cloned();
+                if let Some(data) = data {
+                    let _ = data.set_focus(true).await;
+                }
+                return;
+            }
+            _ => return,
+        };
+        evt.prevent_default();
+
+        let Some(target) = find_item(&id, &focused, target).await else {
+            return;
+        };
+        let (value, data) = {
+            let hierarchy = hierarchy.read();
+            (hierarchy.ids.get(&target).cloned(), hierarchy.mounted.get(&target).cloned())
+        };
+        if let (Some(value), true) = (value, multiple && modifiers.shift() && matches!(key, Key::ArrowDown | Key::ArrowUp)) {
+            state.write().select(&value);
+        }
+        if let Some(data) = data {
+            let _ = data.set_focus(true).await;
+        }
+    };
+
+    let tree = state.read();
+
+    rsx! {
+        div {
+            role: "tree",
+            id: tree.id.as_str(),
+            aria_multiselectable: tree.typ.is_multiple(),
+            aria_disabled: tree.disabled,
+            "data-disabled": tree.disabled,
+
+            onkeydown: onkeydown,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Shares an item with its `TreeGroup` and the items nested inside of it
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TreeItemState {
+    value: Memo<String>,
+    level: usize,
+    expanded: Memo<bool>,
+    /// Whether a `TreeGroup` is placed inside of the item
+    has_group: Signal<bool>,
+}
+
+/// An item in the tree
+///
+/// Place a `TreeGroup` inside of it to nest items, which are only rendered while the item is expanded.
+///
+/// Clicking the item selects it, or toggles it when multiple items can be selected, and expands or collapses it.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`, present when the item can be expanded
+/// - `[data-selected]`: Present when selected
+/// - `[data-disabled]`: Present when disabled
+/// - `[data-loading]`: Present when its children are being loaded
+#[component]
+pub fn TreeItem(
+    /// The unique value of the item
+    ///
+    /// This is what is returned when the `onchange` and `onexpandedchange` events are run
+    #[props(into)]
+    value: String,
+    /// Whether the item is disabled
+    disabled: Option<bool>,
+    /// Whether the item can be expanded, even before its children are loaded. Defaults to whether the item contains
+    /// a `TreeGroup`
+    expandable: Option<bool>,
+    /// Whether the children of the item are being loaded
+    loading: Option<bool>,
+    /// Handler that is run with the value of the item whenever it is expanded
+    ///
+    /// Use it to load the children lazily, async handlers are spawned. Set `loading` while they are loaded
+    onexpand: Option<EventHandler<String>>,
+    /// Text used for typeahead. Defaults to the text content of the item, without its nested items
+    #[props(into)]
+    text_value: Option<String>,
+
+    /// Remaining attributes to add to the item `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TreeState>>();
+    let parent = try_use_context::<TreeItemState>();
+    let parent_value = parent.map(|parent| parent.value);
+    let level = parent.map_or(1, |parent| parent.level + 1);
+    let disabled = state.read().disabled || disabled.unwrap_or_default();
+    let loading = loading.unwrap_or_default();
+
+    let id = use_hook(create_id);
+    let value = use_memo(use_reactive!(|value| value));
+    let has_group = use_signal(|| false);
+    let expandable = expandable.unwrap_or(has_group());
+    let mut hierarchy = state.peek().hierarchy;
+    let expanded = use_hierarchy_item(hierarchy, id.clone(), parent_value, value, expandable, onexpand);
+    let selected = use_memo(move || state.read().is_selected(value.read().as_str()));
+    use_context_provider(|| TreeItemState { value, level, expanded, has_group });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut state) = state.try_write() {
+                if state.focused.as_ref() == Some(&id) {
+                    state.focused = None;
+                }
+            }
+        }
+    });
+
+    let tree = state.read();
+    let tab_stop = tree.is_tab_stop(&id, &value.read());
+    let (posinset, setsize) = hierarchy.read().position(parent_value.map(|parent| parent.read().clone()), &value.read());
+
+    rsx! {
+        div {
+            role: "treeitem",
+            id: id.as_str(),
+            tabindex: if tab_stop && !disabled { 0 } else { -1 },
+            aria_level: level,
+            aria_setsize: setsize,
+            aria_posinset: posinset,
+            aria_expanded: if expandable { Some(expanded()) } else { None },
+            aria_selected: selected(),
+            aria_disabled: disabled,
+            aria_busy: loading,
+            "data-meek-tree-item": true,
+            "data-meek-text-value": text_value,
+            "data-state": if expandable { Some(if expanded() { "open" } else { "closed" }) } else { None },
+            "data-selected": selected(),
+            "data-disabled": disabled,
+            "data-loading": loading,
+
+            onfocus: {
+                let id = id.clone();
+                move |_| state.write().focused = Some(id.clone())
+            },
+            onclick: move |evt| {
+                // Items are nested, keep the click from reaching the parent items
+                evt.stop_propagation();
+                if disabled {
+                    return;
+                }
+                let value = value.peek().clone();
+                state.write().select(&value);
+                hierarchy.write().toggle(&value);
+            },
+            onmounted: {
+                let id = id.clone();
+                move |v: Event<MountedData>| {
+                    hierarchy.write().mounted.insert(id.clone(), v.data());
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Holds the items nested inside of a `TreeItem`
+///
+/// The nested items are only rendered while the item is expanded.
+#[component]
+pub fn TreeGroup(
+    /// Remaining attributes to add to the group `div` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let item = use_context::<TreeItemState>();
+    let mut has_group = item.has_group;
+    use_effect(move || has_group.set(true));
+    use_drop(move || {
+        if let Ok(mut has_group) = has_group.try_write() {
+            *has_group = false;
+        }
+    });
+
+    rsx! {
+        if (item.expanded)() {
+            div {
+                role: "group",
+
+                ..attrs,
+
+                {children}
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::{
+    cell_navigation::{find_cell, CellLayout, CellTarget},
+    create_id,
+    hierarchy::{use_hierarchy_item, Hierarchy},
+    Optional, OptionalMaybeSignal,
+};
+
+/// Which elements of a treegrid can receive focus
+#[derive(Default, Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum TreegridFocus {
+    /// Only rows receive focus
+    #[default]
+    Row,
+    /// Rows and cells receive focus, the arrow keys move into the cells of a row
+    Cell,
+}
+
+impl<A: AsRef<str>> From<A> for TreegridFocus {
+    fn from(value: A) -> Self {
+        match value.as_ref().to_ascii_lowercase().as_str() {
+            "row" => Self::Row,
+            "cell" => Self::Cell,
+            other => panic!("unknown treegrid focus: '{other}'")
+        }
+    }
+}
+
+/// Rows and cells of a treegrid, rows receive focus themselves
+const LAYOUT: CellLayout = CellLayout {
+    rows: "[data-meek-treegrid-row]",
+    cells: "[data-meek-treegrid-cell]",
+    row_focus: true,
+};
+
+/// Handles the contextual state of a treegrid
+#[derive(Debug)]
+struct TreegridState {
+    id: String,
+    pub focus: TreegridFocus,
+
+    /// Id of the row or cell that currently has focus
+    focused: Option<String>,
+    /// The rendered rows and which of them are expanded. Also holds the mounted data of the cells
+    hierarchy: Signal<Hierarchy>,
+}
+
+impl TreegridState {
+    /// Whether the row or cell is the one that is reached when tabbing into the treegrid
+    fn is_tab_stop(&self, id: &str) -> bool {
+        let hierarchy = self.hierarchy.read();
+        if let Some(focused) = self.focused.as_deref().filter(|focused| hierarchy.mounted.contains_key(*focused)) {
+            return focused == id;
+        }
+        hierarchy.first().is_some_and(|first| hierarchy.ids.get(id) == Some(first))
+    }
+}
+
+/// Treegrid
+///
+/// A table whose rows can be expanded to reveal nested rows.
+///
+/// Place `TreegridRow`s inside of it, or inside of a `thead`/`tbody` with your own header row.
+///
+/// # Accessibility
+///
+/// **Keyboard Interaction**
+///
+/// When a row has focus:
+///
+/// - `<Tab>`: Moves focus to the row that had focus last, or the first row.
+/// - `<ArrowDown>`: Moves focus to the next visible row.
+/// - `<ArrowUp>`: Moves focus to the previous visible row.
+/// - `<ArrowRight>`: Expands a collapsed row, or moves focus to the first cell when cells receive focus.
+/// - `<ArrowLeft>`: Collapses an expanded row, or moves focus to the parent row.
+/// - `<Home>`: Moves focus to the first row.
+/// - `<End>`: Moves focus to the last visible row.
+/// - `<Enter>`: Expands or collapses the row.
+///
+/// When a cell has focus:
+///
+/// - `<ArrowRight>`: Moves focus to the next cell in the row.
+/// - `<ArrowLeft>`: Moves focus to the previous cell in the row, or to the row from the first cell.
+/// - `<ArrowDown>`: Moves focus to the cell in the same column of the next visible row.
+/// - `<ArrowUp>`: Moves focus to the cell in the same column of the previous visible row.
+/// - `<Home>`: Moves focus to the first cell in the row.
+/// - `<End>`: Moves focus to the last cell in the row.
+/// - `<Ctrl+Home>`: Moves focus to the cell in the same column of the first row.
+/// - `<Ctrl+End>`: Moves focus to the cell in the same column of the last visible row.
+#[component]
+pub fn Treegrid(
+    /// Expanded row(s)
+    ///
+    /// When a signal is passed it is kept in sync with the expanded rows
+    #[props(into, default)]
+    expanded: OptionalMaybeSignal<HashSet<String>>,
+    /// Default row(s) to expand
+    #[props(into, default)]
+    default_expanded: Optional<Vec<String>>,
+    /// Handler that is run when rows are expanded or collapsed
+    onexpandedchange: Option<EventHandler<HashSet<String>>>,
+    /// Whether only rows, or rows and cells receive focus. Defaults to `row`
+    #[props(into, default)]
+    focus: TreegridFocus,
+    /// User defined ID of the treegrid
+    #[props(into)]
+    id: Option<String>,
+
+    /// Remaining attributes to add to the treegrid `table` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let current = expanded.clone().as_signal(default_expanded.unwrap_or_default().into_iter().collect());
+    let mut state = use_context_provider(|| {
+        let id = id.unwrap_or(create_id());
+        Signal::new(TreegridState {
+            hierarchy: Signal::new(Hierarchy::new(id.clone(), LAYOUT.rows, current, onexpandedchange)),
+            id,
+            focus,
+            focused: None,
+        })
+    });
+    let mut hierarchy = state.peek().hierarchy;
+
+    use_effect(use_reactive!(|expanded| {
+        if let OptionalMaybeSignal::Normal(expanded) = expanded {
+            hierarchy.write().sync_expanded(expanded);
+        }
+    }));
+
+    use_effect(use_reactive!(|focus| state.write().focus = focus));
+
+    let onkeydown = move |evt: Event<KeyboardData>| async move {
+        let (id, focused, row, cells) = {
+            let state = state.read();
+            let Some(focused) = state.focused.clone() else {
+                return;
+            };
+            let row = state.hierarchy.read().ids.get(&focused).cloned();
+            (state.id.clone(), focused, row, state.focus.is_cell())
+        };
+
+        let modifiers = evt.modifiers();
+        let ctrl = modifiers.ctrl() || modifiers.meta();
+        let target = match (evt.key(), row) {
+            (Key::ArrowDown, _) => CellTarget::NextRow,
+            (Key::ArrowUp, _) => CellTarget::PreviousRow,
+            (Key::Home, None) if !ctrl => CellTarget::FirstCell,
+            (Key::End, None) if !ctrl => CellTarget::LastCell,
+            (Key::Home, _) => CellTarget::FirstRow,
+            (Key::End, _) => CellTarget::LastRow,
+            (Key::ArrowRight, None) => CellTarget::NextCell,
+            (Key::ArrowLeft, None) => CellTarget::PreviousCell,
+            (Key::ArrowRight, Some(row)) => {
+                evt.prevent_default();
+                let mut hierarchy = hierarchy.write();
+                if hierarchy.is_expandable(&row) && !hierarchy.is_expanded(&row) {
+                    hierarchy.set_expanded([&row], true);
+                    return;
+                }
+                if !cells {
+                    return;
+                }
+                CellTarget::FirstCell
+            }
+            (Key::ArrowLeft, Some(row)) => {
+                evt.prevent_default();
+                let mut hierarchy = hierarchy.write();
+                if hierarchy.is_expanded(&row) {
+                    hierarchy.set_expanded([&row], false);
+                    return;
+                }
+                CellTarget::ParentRow
+            }
+            (Key::Enter, Some(row)) => {
+                evt.prevent_default();
+                hierarchy.write().toggle(&row);
+                return;
+            }
+            _ => return,
+        };
+        evt.prevent_default();
+
+        let Some(target) = find_cell(&id, &focused, LAYOUT, target).await else {
+            return;
+        };
+        let data = hierarchy.read().mounted.get(&target).cloned();
+        if let Some(data) = data {
+            let _ = data.set_focus(true).await;
+        }
+    };
+
+    let grid = state.read();
+
+    rsx! {
+        table {
+            role: "treegrid",
+            id: grid.id.as_str(),
+
+            onkeydown: onkeydown,
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
+
+/// Shares a row with its cells and its child rows
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TreegridRowState {
+    value: Memo<String>,
+    level: usize,
+}
+
+/// A row in the treegrid
+///
+/// Child rows are passed through `rows` and are rendered after the row while it is expanded.
+///
+/// # Data Attributes
+///
+/// - `[data-state]`: `"open"` | `"closed"`, present when the row can be expanded
+/// - `[data-loading]`: Present when its child rows are being loaded
+#[component]
+pub fn TreegridRow(
+    /// The unique value of the row
+    ///
+    /// This is what is returned when the `onexpandedchange` event is run
+    #[props(into)]
+    value: String,
+    /// Whether the row can be expanded, even before its child rows are loaded. Defaults to whether `rows` is set
+    expandable: Option<bool>,
+    /// Whether the child rows are being loaded
+    loading: Option<bool>,
+    /// Handler that is run with the value of the row whenever it is expanded
+    ///
+    /// Use it to load the child rows lazily, async handlers are spawned. Set `loading` while they are loaded
+    onexpand: Option<EventHandler<String>>,
+    /// Rows nested under this row
+    #[props(default = VNode::empty())]
+    rows: Element,
+
+    /// Remaining attributes to add to the row `tr` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TreegridState>>();
+    let parent = try_use_context::<TreegridRowState>();
+    let parent_value = parent.map(|parent| parent.value);
+    let level = parent.map_or(1, |parent| parent.level + 1);
+    let loading = loading.unwrap_or_default();
+
+    let id = use_hook(create_id);
+    let value = use_memo(use_reactive!(|value| value));
+    let expandable = expandable.unwrap_or(rows != VNode::empty());
+    let mut hierarchy = state.peek().hierarchy;
+    let expanded = use_hierarchy_item(hierarchy, id.clone(), parent_value, value, expandable, onexpand);
+    use_context_provider(|| TreegridRowState { value, level });
+
+    let grid = state.read();
+    let tab_stop = grid.is_tab_stop(&id);
+    let (posinset, setsize) = hierarchy.read().position(parent_value.map(|parent| parent.read().clone()), &value.read());
+
+    rsx! {
+        tr {
+            role: "row",
+            id: id.as_str(),
+            tabindex: if tab_stop { 0 } else { -1 },
+            aria_level: level,
+            aria_setsize: setsize,
+            aria_posinset: posinset,
+            aria_expanded: if expandable { Some(expanded()) } else { None },
+            aria_busy: loading,
+            "data-meek-treegrid-row": true,
+            "data-state": if expandable { Some(if expanded() { "open" } else { "closed" }) } else { None },
+            "data-loading": loading,
+
+            onfocus: {
+                let id = id.clone();
+                move |_| state.write().focused = Some(id.clone())
+            },
+            onclick: move |_| {
+                let value = value.peek().clone();
+                hierarchy.write().toggle(&value);
+            },
+            onmounted: {
+                let id = id.clone();
+                move |v: Event<MountedData>| {
+                    hierarchy.write().mounted.insert(id.clone(), v.data());
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+        if expanded() {
+            {rows}
+        }
+    }
+}
+
+/// A cell in a row of the treegrid
+///
+/// Cells only receive focus when the treegrid's `focus` is set to `cell`.
+#[component]
+pub fn TreegridCell(
+    /// Remaining attributes to add to the cell `td` tag
+    #[props(extends = GlobalAttributes)]
+    attrs: Vec<Attribute>,
+
+    children: Element,
+) -> Element {
+    let mut state = use_context::<Signal<TreegridState>>();
+    let mut hierarchy = state.peek().hierarchy;
+    let id = use_hook(create_id);
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            if let Ok(mut hierarchy) = hierarchy.try_write() {
+                hierarchy.mounted.remove(&id);
+            }
+        }
+    });
+
+    let grid = state.read();
+    let tabindex = grid.focus.is_cell().then(|| if grid.is_tab_stop(&id) { 0 } else { -1 });
+
+    rsx! {
+        td {
+            role: "gridcell",
+            id: id.as_str(),
+            tabindex: tabindex,
+            "data-meek-treegrid-cell": true,
+
+            onfocus: {
+                let id = id.clone();
+                move |_| state.write().focused = Some(id.clone())
+            },
+            onmounted: {
+                let id = id.clone();
+                move |v: Event<MountedData>| {
+                    hierarchy.write().mounted.insert(id.clone(), v.data());
+                }
+            },
+
+            ..attrs,
+
+            {children}
+        }
+    }
+}
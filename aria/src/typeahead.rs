@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+
+/// Find the item inside of the container whose text starts with the keys typed in quick succession
+///
+/// Items are matched with `selector` and must belong to the container, not to a nested container with the same
+/// role. Their text is read from `data-meek-text-value`, falling back to their text content without the text of
+/// nested groups. Typing the same character repeatedly cycles through the items starting with it. The typed keys
+/// are remembered per container for one second.
+///
+/// Returns the id of the matching item, unless it is the `current` item.
+pub(crate) async fn typeahead(container: &str, selector: &str, key: &str, current: Option<&str>) -> Option<String> {
+    let current = current.unwrap_or_default();
+    let mut eval = document::eval(&format!(r#"
+        const container = document.getElementById({container:?});
+        if (!container) {{
+            dioxus.send(null);
+            return;
+        }}
+
+        window.__meekTypeahead ??= {{}};
+        const state = window.__meekTypeahead[{container:?}] ??= {{ search: "", timer: 0 }};
+        clearTimeout(state.timer);
+        state.search += {key:?};
+        state.timer = setTimeout(() => {{ state.search = ""; }}, 1000);
+
+        const role = container.getAttribute("role");
+        const items = [...container.querySelectorAll(`{selector}`)]
+            .filter((item) => !role || item.parentElement.closest(`[role="${{role}}"]`) === container);
+        const content = (item) => [...item.childNodes]
+            .filter((node) => node.nodeType !== Node.ELEMENT_NODE || node.getAttribute("role") !== "group")
+            .map((node) => node.textContent)
+            .join("");
+        const text = (item) => (item.dataset.meekTextValue ?? content(item)).trim().toLowerCase();
+
+        const repeated = [...state.search].every((char) => char === state.search[0]);
+        const search = (repeated ? state.search[0] : state.search).toLowerCase();
+
+        const start = items.findIndex((item) => item.id === {current:?});
+        let wrapped = start >= 0 ? [...items.slice(start), ...items.slice(0, start)] : items;
+        // A single character moves past the current item, longer searches may keep matching it
+        if (search.length === 1) wrapped = wrapped.filter((item) => item.id !== {current:?});
+
+        const match = wrapped.find((item) => text(item).startsWith(search));
+        dioxus.send(match && match.id !== {current:?} ? match.id : null);
+    "#));
+
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
@@ -3,8 +3,6 @@ use dioxus::prelude::*;
 use dioxus_logger::tracing::Level;
 use views::Showcase;
 
-use meek_aria::Portal;
-
 mod views;
 
 #[derive(Debug, Clone, Routable, PartialEq)]
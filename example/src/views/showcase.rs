@@ -1,17 +1,73 @@
+use std::collections::HashSet;
+
 use dioxus::prelude::*;
 
-use lucide_dioxus::{Bold, Check};
+use crate::Route;
+
+use lucide_dioxus::{AlignCenter, AlignLeft, AlignRight, Bold, Check, ChevronLeft, ChevronRight, Italic, Pause, Play, Underline};
 
 use meek_aria::Button;
 use meek_aria::Checkbox;
+use meek_aria::{ Switch, SwitchThumb };
+use meek_aria::{ Slider, SliderTrack, SliderRange, SliderThumb };
+use meek_aria::{ Popover, PopoverTrigger, PopoverPortal, PopoverContent, PopoverClose, PopoverArrow };
+use meek_aria::{
+    DropdownMenu, DropdownMenuTrigger, DropdownMenuPortal, DropdownMenuContent, DropdownMenuItem, DropdownMenuGroup,
+    DropdownMenuLabel, DropdownMenuSeparator, DropdownMenuCheckboxItem, DropdownMenuRadioGroup, DropdownMenuRadioItem,
+    DropdownMenuItemIndicator, DropdownMenuSub, DropdownMenuSubTrigger, DropdownMenuSubContent,
+};
+use meek_aria::{
+    ContextMenu, ContextMenuTrigger, ContextMenuPortal, ContextMenuContent, ContextMenuItem, ContextMenuSeparator,
+    ContextMenuSub, ContextMenuSubTrigger, ContextMenuSubContent,
+};
+use meek_aria::{
+    Select, SelectTrigger, SelectValue, SelectIcon, SelectPortal, SelectContent, SelectViewport, SelectItem,
+    SelectItemText, SelectItemIndicator, SelectGroup, SelectLabel, SelectSeparator, SelectScrollUpButton,
+    SelectScrollDownButton,
+};
+use meek_aria::{
+    Combobox, ComboboxAnchor, ComboboxInput, ComboboxTrigger, ComboboxChip, ComboboxChipRemove, ComboboxPortal,
+    ComboboxContent, ComboboxItem, ComboboxItemIndicator, ComboboxEmpty, ComboboxLoading,
+};
+use meek_aria::{ Listbox, ListboxOption, ListboxOptionIndicator, ListboxGroup, ListboxGroupLabel };
+use meek_aria::{ Tree, TreeItem, TreeGroup };
+use meek_aria::{ Treegrid, TreegridRow, TreegridCell };
+use meek_aria::{ Grid, GridRow, GridCell, GridColumnHeader, GridSort };
+use meek_aria::{ Toolbar, ToolbarButton, ToolbarLink, ToolbarSeparator, ToolbarToggleGroup, ToolbarToggleItem };
+use meek_aria::{ ToggleGroup, ToggleGroupItem, Orientation };
+use meek_aria::{ Breadcrumb, BreadcrumbList, BreadcrumbItem, BreadcrumbLink, BreadcrumbPage, BreadcrumbSeparator };
+use meek_aria::{ Carousel, CarouselViewport, CarouselSlide, CarouselPrevious, CarouselNext, CarouselPicker, CarouselRotationControl };
+use meek_aria::{ Collapsible, CollapsibleTrigger, CollapsibleContent };
+use meek_aria::{ TooltipProvider, Tooltip, TooltipTrigger, TooltipPortal, TooltipContent, TooltipArrow };
 use meek_aria::{ Portal, Teleport };
 use meek_aria::{ Accordian, AccordianItem, AccordianHeader, AccordianContent, AccordianTrigger };
 use meek_aria::{ AlertDialog, AlertDialogTrigger, AlertDialogContent, AlertDialogTitle, AlertDialogDescription, AlertDialogCancel, AlertDialogAction };
+use meek_aria::{ Dialog, DialogTrigger, DialogContent, DialogTitle, DialogDescription, DialogClose };
+use meek_aria::{ Tabs, TabsList, TabsTrigger, TabsContent };
+use meek_aria::{ RadioGroup, RadioGroupItem, RadioGroupIndicator };
 
 #[component]
 pub fn Showcase() -> Element {
     let mut toggled = use_signal(|| false);
     let mut alert_open = use_signal(|| false);
+    let mut accordian_open = use_signal(|| HashSet::from(["item-1".to_string()]));
+
+    let mut country_search = use_signal(String::new);
+    let countries = use_resource(move || async move {
+        let search = country_search().to_lowercase();
+        // Pretend the countries come from a server
+        let mut eval = document::eval("setTimeout(() => dioxus.send(true), 300)");
+        let _ = eval.recv::<bool>().await;
+        ["Belgium", "Brazil", "Canada", "Denmark", "France", "Germany", "Japan", "Netherlands", "Portugal", "Spain"]
+            .into_iter()
+            .filter(|country| country.to_lowercase().contains(&search))
+            .collect::<Vec<_>>()
+    });
+    let fruits = use_signal(|| vec!["apple".to_string()]);
+    let mut dependencies = use_signal(|| None::<Vec<&'static str>>);
+    let mut dependencies_loading = use_signal(|| false);
+    let mut users_ascending = use_signal(|| true);
+    let alignment = use_signal(|| vec!["center".to_string()]);
 
     let accordian_items = [
         ("item-1", "Item 1"),
@@ -29,6 +85,109 @@ pub fn Showcase() -> Element {
                     background-color: red;
                     border-color: red;
                 }}
+                dialog[data-state="closed"] {{
+                    animation: fade-out 150ms ease-in;
+                }}
+                @keyframes fade-out {{
+                    from {{ opacity: 1; }}
+                    to {{ opacity: 0; }}
+                }}
+                .accordian-content {{
+                    overflow: hidden;
+                }}
+                .accordian-content[data-state="open"] {{
+                    animation: slide-down 200ms ease-out;
+                }}
+                .accordian-content[data-state="closed"] {{
+                    animation: slide-up 200ms ease-out;
+                }}
+                @keyframes slide-down {{
+                    from {{ height: 0; }}
+                    to {{ height: var(--meek-accordian-content-height); }}
+                }}
+                @keyframes slide-up {{
+                    from {{ height: var(--meek-accordian-content-height); }}
+                    to {{ height: 0; }}
+                }}
+                .collapsible-content {{
+                    overflow: hidden;
+                }}
+                .collapsible-content[data-state="open"] {{
+                    animation: collapsible-down 200ms ease-out;
+                }}
+                .collapsible-content[data-state="closed"] {{
+                    animation: collapsible-up 200ms ease-out;
+                }}
+                @keyframes collapsible-down {{
+                    from {{ height: 0; }}
+                    to {{ height: var(--meek-collapsible-content-height); }}
+                }}
+                @keyframes collapsible-up {{
+                    from {{ height: var(--meek-collapsible-content-height); }}
+                    to {{ height: 0; }}
+                }}
+                .popover-content {{
+                    transform-origin: var(--meek-popper-transform-origin);
+                    max-height: var(--meek-popper-available-height);
+                }}
+                .popover-content[data-state="closed"] {{
+                    animation: fade-out 150ms ease-in;
+                }}
+                .menu-item {{
+                    position: relative;
+                    padding: 0.25rem 0.5rem 0.25rem 1.5rem;
+                    border-radius: 0.25rem;
+                    outline: none;
+                    user-select: none;
+                }}
+                .menu-item[data-highlighted] {{
+                    background-color: #f43f5e;
+                    color: white;
+                }}
+                .menu-item[data-disabled] {{
+                    opacity: 0.5;
+                }}
+                .listbox-option[data-state="checked"] {{
+                    background-color: #f43f5e;
+                    color: white;
+                }}
+                .tree-item {{
+                    outline: none;
+                    user-select: none;
+                }}
+                .tree-item[data-selected] > .tree-label {{
+                    background-color: #f43f5e;
+                }}
+                .tree-item:focus-visible > .tree-label {{
+                    outline: 1px solid #f43f5e;
+                }}
+                .treegrid-row:focus-visible, .treegrid-row td:focus-visible {{
+                    outline: 1px solid #f43f5e;
+                    outline-offset: -1px;
+                }}
+                .grid-row[data-selected] {{
+                    background-color: #f43f5e;
+                }}
+                .grid-row th:focus-visible, .grid-row td:focus-visible {{
+                    outline: 1px solid #f43f5e;
+                    outline-offset: -1px;
+                }}
+                .listbox-option:focus {{
+                    outline: 1px solid #f43f5e;
+                    outline-offset: -1px;
+                }}
+                .carousel-rotation[data-state="playing"] .play, .carousel-rotation[data-state="paused"] .pause {{
+                    display: none;
+                }}
+                [data-meek-carousel-tab] {{
+                    width: 0.75rem;
+                    height: 0.75rem;
+                    border-radius: 9999px;
+                    background-color: #d4d4d8;
+                }}
+                [data-meek-carousel-tab][data-state="active"] {{
+                    background-color: #f43f5e;
+                }}
             "#}
             div {
                 class: "p-4",
@@ -66,7 +225,64 @@ pub fn Showcase() -> Element {
                             }
                             AccordianContent {
                                 id: format!("accord-{i}-content"),
-                                class: "border border-sky-700 p-2",
+                                class: "accordian-content border border-sky-700 p-2",
+                                "{item.1} Content"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Controlled Accordian" }
+                Button {
+                    class: "",
+                    toggled: toggled(),
+                    onclick: move |_| toggled.toggle(), 
+                    aria_label: "Toggle bold",
+                    Bold { class: "w-4 h-4" }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Controlled Accordian" }
+                div {
+                    class: "flex gap-2 mb-2",
+                    Button {
+                        class: "border px-2 py-1",
+                        onclick: move |_| accordian_open.set(accordian_items.iter().map(|item| item.0.to_string()).collect()),
+                        "Expand All"
+                    }
+                    Button {
+                        class: "border px-2 py-1",
+                        onclick: move |_| accordian_open.write().clear(),
+                        "Collapse All"
+                    }
+                }
+                Accordian {
+                    collapsible: true,
+                    r#type: "multiple",
+                    value: accordian_open,
+                    onchange: move |items| {
+                        println!("{items:?}");
+                    },
+                    for (i, item) in accordian_items.iter().enumerate() {
+                        AccordianItem {
+                            id: format!("controlled-accord-{i}"),
+                            key: item.0,
+                            value: item.0,
+                            AccordianHeader { 
+                                AccordianTrigger {
+                                    id: format!("controlled-accord-{i}-trigger"),
+                                    class: "group w-full border flex justify-between px-6 focus-visible:border-rose-500 outline-none focus:outline-none",
+                                    {item.1}
+                                    span { class: "group-data-[state=open]:hidden", ">" }
+                                    span { class: "group-data-[state=closed]:hidden", "v" }
+                                }
+                            }
+                            AccordianContent {
+                                id: format!("controlled-accord-{i}-content"),
+                                class: "accordian-content border border-sky-700 p-2",
                                 "{item.1} Content"
                             }
                         }
@@ -107,6 +323,77 @@ pub fn Showcase() -> Element {
                     }
                 }
             }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Dialog" }
+                Dialog {
+                    modal: false,
+                    DialogTrigger {
+                        class: "border px-2 py-1",
+                        "Open Dialog"
+                    }
+                    DialogContent { class: "w-50 h-50 rounded-md p-4",
+                        DialogTitle { class: "text-2xl font-bold",
+                            "Dialog"
+                        }
+                        DialogDescription {
+                            "This is a non-modal dialog. Click outside of it or press escape to close it."
+                        }
+                        div {
+                            class: "mt-2 flex gap-2 justify-end",
+                            DialogClose {
+                                class: "border px-2 py-1 rounded-md hover:bg-gray-100 focus:bg-gray-300 focus:outline-none",
+                                "Close"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Tabs" }
+                Tabs {
+                    default: "account",
+                    TabsList {
+                        class: "flex gap-2 mb-2",
+                        TabsTrigger {
+                            class: "border px-2 py-1 data-[state=active]:border-rose-500",
+                            value: "account",
+                            "Account"
+                        }
+                        TabsTrigger {
+                            class: "border px-2 py-1 data-[state=active]:border-rose-500",
+                            value: "password",
+                            "Password"
+                        }
+                    }
+                    TabsContent { value: "account", "Make changes to your account here." }
+                    TabsContent { value: "password", "Change your password here." }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Radio Group" }
+                RadioGroup {
+                    class: "flex flex-col gap-2",
+                    name: "density",
+                    default: "comfortable",
+                    aria_label: "View density",
+                    for (value, label) in [("default", "Default"), ("comfortable", "Comfortable"), ("compact", "Compact")] {
+                        div {
+                            key: value,
+                            class: "flex items-center gap-2",
+                            RadioGroupItem {
+                                id: "density-{value}",
+                                class: "bg-white w-5 h-5 rounded-full flex items-center justify-center border",
+                                value: value,
+                                RadioGroupIndicator { class: "block w-2 h-2 rounded-full bg-rose-500" }
+                            }
+                            label { r#for: "density-{value}", "{label}" }
+                        }
+                    }
+                }
+            }
             Teleport {
                 div {
                     class: "p-4",
@@ -130,6 +417,599 @@ pub fn Showcase() -> Element {
                     Check { class: "hidden group-data-[state=checked]:block w-4 h-4 text-green-500" }
                 }
             }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Switch" }
+                Switch {
+                    class: "group w-10 h-6 rounded-full border bg-gray-700 data-[state=checked]:bg-rose-500",
+                    aria_label: "Airplane mode",
+                    SwitchThumb { class: "block w-4 h-4 rounded-full bg-white translate-x-1 data-[state=checked]:translate-x-5 transition-transform" }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Slider" }
+                Slider {
+                    class: "flex items-center w-64 h-5 select-none",
+                    default: vec![25.0, 75.0],
+                    step: 5.0,
+                    min_steps_between_thumbs: 1.0,
+                    name: "price",
+                    SliderTrack {
+                        class: "grow h-1 rounded-full bg-gray-700",
+                        SliderRange { class: "h-full rounded-full bg-rose-500" }
+                    }
+                    SliderThumb { class: "block w-5 h-5 rounded-full bg-white shadow", aria_label: "Minimum price" }
+                    SliderThumb { class: "block w-5 h-5 rounded-full bg-white shadow", aria_label: "Maximum price" }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Popover" }
+                Popover {
+                    PopoverTrigger { class: "border px-2 py-1", "Edit dimensions" }
+                    PopoverPortal {
+                        PopoverContent {
+                            class: "popover-content flex flex-col gap-2 p-4 rounded bg-white text-black shadow",
+                            side: "top",
+                            side_offset: 4.0,
+                            collision_padding: 8.0,
+                            label { class: "flex justify-between gap-4", "Width", input { class: "border px-1", value: "100%" } }
+                            label { class: "flex justify-between gap-4", "Height", input { class: "border px-1", value: "25px" } }
+                            PopoverClose { class: "self-end border px-2 py-1", "Done" }
+                            PopoverArrow { class: "text-white" }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Tooltip" }
+                TooltipProvider {
+                    delay_duration: 400,
+                    Tooltip {
+                        TooltipTrigger {
+                            class: "border p-1 data-[state=delayed-open]:bg-gray-700",
+                            aria_label: "Toggle bold",
+                            onclick: move |_| toggled.toggle(),
+                            Bold { class: "w-4 h-4" }
+                        }
+                        TooltipPortal {
+                            TooltipContent {
+                                class: "popover-content px-2 py-1 rounded bg-white text-black text-sm",
+                                side_offset: 4.0,
+                                "Toggle bold"
+                                TooltipArrow { class: "text-white" }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Dropdown Menu" }
+                DropdownMenu {
+                    DropdownMenuTrigger { class: "border px-2 py-1", "Options" }
+                    DropdownMenuPortal {
+                        DropdownMenuContent {
+                            class: "popover-content min-w-48 p-1 rounded bg-white text-black shadow outline-none",
+                            side_offset: 4.0,
+                            DropdownMenuItem { class: "menu-item", "New Tab" }
+                            DropdownMenuItem { class: "menu-item", "New Window" }
+                            DropdownMenuItem { class: "menu-item", disabled: true, "New Private Window" }
+                            DropdownMenuSub {
+                                DropdownMenuSubTrigger { class: "menu-item", "More Tools" }
+                                DropdownMenuPortal {
+                                    DropdownMenuSubContent {
+                                        class: "popover-content min-w-40 p-1 rounded bg-white text-black shadow outline-none",
+                                        side_offset: 2.0,
+                                        DropdownMenuItem { class: "menu-item", "Save Page As…" }
+                                        DropdownMenuItem { class: "menu-item", "Create Shortcut…" }
+                                        DropdownMenuSeparator { class: "h-px my-1 bg-gray-300" }
+                                        DropdownMenuItem { class: "menu-item", "Developer Tools" }
+                                    }
+                                }
+                            }
+                            DropdownMenuSeparator { class: "h-px my-1 bg-gray-300" }
+                            DropdownMenuCheckboxItem {
+                                class: "menu-item",
+                                default: true,
+                                DropdownMenuItemIndicator { class: "absolute left-1", "✓" }
+                                "Show Bookmarks"
+                            }
+                            DropdownMenuSeparator { class: "h-px my-1 bg-gray-300" }
+                            DropdownMenuGroup {
+                                DropdownMenuLabel { class: "pl-6 text-xs text-gray-500", "People" }
+                                DropdownMenuRadioGroup {
+                                    default: "pedro",
+                                    DropdownMenuRadioItem {
+                                        class: "menu-item",
+                                        value: "pedro",
+                                        DropdownMenuItemIndicator { class: "absolute left-1", "•" }
+                                        "Pedro Duarte"
+                                    }
+                                    DropdownMenuRadioItem {
+                                        class: "menu-item",
+                                        value: "colm",
+                                        DropdownMenuItemIndicator { class: "absolute left-1", "•" }
+                                        "Colm Tuite"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Context Menu" }
+                ContextMenu {
+                    ContextMenuTrigger {
+                        class: "flex items-center justify-center w-72 h-36 border border-dashed rounded select-none",
+                        tabindex: 0,
+                        "Right click here"
+                    }
+                    ContextMenuPortal {
+                        ContextMenuContent {
+                            class: "popover-content min-w-48 p-1 rounded bg-white text-black shadow outline-none",
+                            ContextMenuItem { class: "menu-item", "Back" }
+                            ContextMenuItem { class: "menu-item", disabled: true, "Forward" }
+                            ContextMenuItem { class: "menu-item", "Reload" }
+                            ContextMenuSub {
+                                ContextMenuSubTrigger { class: "menu-item", "More Tools" }
+                                ContextMenuPortal {
+                                    ContextMenuSubContent {
+                                        class: "popover-content min-w-40 p-1 rounded bg-white text-black shadow outline-none",
+                                        side_offset: 2.0,
+                                        ContextMenuItem { class: "menu-item", "Save Page As…" }
+                                        ContextMenuItem { class: "menu-item", "Developer Tools" }
+                                    }
+                                }
+                            }
+                            ContextMenuSeparator { class: "h-px my-1 bg-gray-300" }
+                            ContextMenuItem { class: "menu-item", "Print…" }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Select" }
+                div {
+                    class: "flex gap-4",
+                    for position in ["item-aligned", "popper"] {
+                        Select {
+                            key: "{position}",
+                            name: "fruit",
+                            default: "banana",
+                            SelectTrigger {
+                                class: "inline-flex items-center justify-between gap-2 min-w-40 border px-2 py-1",
+                                SelectValue { placeholder: "Pick a fruit…" }
+                                SelectIcon {}
+                            }
+                            SelectPortal {
+                                SelectContent {
+                                    class: "popover-content p-1 rounded bg-white text-black shadow outline-none",
+                                    position: position,
+                                    side_offset: 4.0,
+                                    SelectScrollUpButton { class: "flex justify-center text-xs" }
+                                    SelectViewport {
+                                        SelectGroup {
+                                            SelectLabel { class: "pl-6 text-xs text-gray-500", "Fruits" }
+                                            for fruit in ["Apple", "Banana", "Blueberry", "Grapes", "Pineapple"] {
+                                                SelectItem {
+                                                    key: "{fruit}",
+                                                    class: "menu-item",
+                                                    value: fruit.to_lowercase(),
+                                                    SelectItemIndicator { class: "absolute left-1", "✓" }
+                                                    SelectItemText { "{fruit}" }
+                                                }
+                                            }
+                                        }
+                                        SelectSeparator { class: "h-px my-1 bg-gray-300" }
+                                        SelectGroup {
+                                            SelectLabel { class: "pl-6 text-xs text-gray-500", "Vegetables" }
+                                            for vegetable in ["Aubergine", "Broccoli", "Carrot", "Courgette", "Leek"] {
+                                                SelectItem {
+                                                    key: "{vegetable}",
+                                                    class: "menu-item",
+                                                    value: vegetable.to_lowercase(),
+                                                    disabled: vegetable == "Carrot",
+                                                    SelectItemIndicator { class: "absolute left-1", "✓" }
+                                                    SelectItemText { "{vegetable}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SelectScrollDownButton { class: "flex justify-center text-xs" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Combobox" }
+                div {
+                    class: "flex gap-4 items-start",
+                    Combobox {
+                        name: "country",
+                        oninputchange: move |search| country_search.set(search),
+                        // The countries are already filtered while they are fetched
+                        filter: move |_: (String, String)| true,
+                        loading: !countries.finished(),
+                        div {
+                            class: "inline-flex border",
+                            ComboboxInput { class: "px-2 py-1 outline-none", placeholder: "Country…" }
+                            ComboboxTrigger { class: "px-2", "▼" }
+                        }
+                        ComboboxPortal {
+                            ComboboxContent {
+                                class: "popover-content min-w-48 p-1 overflow-auto rounded bg-white text-black shadow",
+                                side_offset: 4.0,
+                                ComboboxLoading { class: "px-2 py-1 text-sm text-gray-500", "Loading…" }
+                                ComboboxEmpty { class: "px-2 py-1 text-sm text-gray-500", "No countries found" }
+                                for country in countries.cloned().unwrap_or_default() {
+                                    ComboboxItem {
+                                        key: "{country}",
+                                        class: "menu-item",
+                                        value: country.to_lowercase(),
+                                        text_value: country,
+                                        ComboboxItemIndicator { class: "absolute left-1", "✓" }
+                                        "{country}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Combobox {
+                        r#type: "multiple",
+                        autocomplete: "both",
+                        value: fruits,
+                        ComboboxAnchor {
+                            class: "flex flex-wrap items-center gap-1 w-72 border px-1 py-1",
+                            for fruit in fruits() {
+                                ComboboxChip {
+                                    key: "{fruit}",
+                                    class: "inline-flex items-center gap-1 px-1 rounded bg-gray-200 text-sm",
+                                    value: fruit,
+                                    ComboboxChipRemove { class: "text-xs" }
+                                }
+                            }
+                            ComboboxInput { class: "flex-1 min-w-16 outline-none", placeholder: "Fruits…" }
+                        }
+                        ComboboxPortal {
+                            ComboboxContent {
+                                class: "popover-content min-w-48 p-1 overflow-auto rounded bg-white text-black shadow",
+                                side_offset: 4.0,
+                                ComboboxEmpty { class: "px-2 py-1 text-sm text-gray-500", "No fruits found" }
+                                for fruit in ["Apple", "Banana", "Blueberry", "Grapes", "Pineapple"] {
+                                    ComboboxItem {
+                                        key: "{fruit}",
+                                        class: "menu-item",
+                                        value: fruit.to_lowercase(),
+                                        text_value: fruit,
+                                        ComboboxItemIndicator { class: "absolute left-1", "✓" }
+                                        "{fruit}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Listbox" }
+                div {
+                    class: "flex gap-4 items-start",
+                    Listbox {
+                        class: "w-48 p-1 rounded bg-white text-black",
+                        aria_label: "Favorite color",
+                        default: vec!["green".to_string()],
+                        for color in ["Red", "Green", "Blue", "Purple"] {
+                            ListboxOption {
+                                key: "{color}",
+                                class: "menu-item listbox-option",
+                                value: color.to_lowercase(),
+                                disabled: color == "Purple",
+                                ListboxOptionIndicator { class: "absolute left-1", "✓" }
+                                "{color}"
+                            }
+                        }
+                    }
+                    Listbox {
+                        class: "w-48 p-1 rounded bg-white text-black",
+                        aria_label: "Groceries",
+                        r#type: "multiple",
+                        for (group, items) in [("Fruits", ["Apple", "Banana", "Cherry"]), ("Vegetables", ["Carrot", "Leek", "Potato"])] {
+                            ListboxGroup {
+                                key: "{group}",
+                                ListboxGroupLabel { class: "px-2 py-1 text-xs text-gray-500", "{group}" }
+                                for item in items {
+                                    ListboxOption {
+                                        key: "{item}",
+                                        class: "menu-item listbox-option",
+                                        value: item.to_lowercase(),
+                                        ListboxOptionIndicator { class: "absolute left-1", "✓" }
+                                        "{item}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Tree" }
+                Tree {
+                    class: "w-64",
+                    aria_label: "Project",
+                    default_expanded: vec!["src".to_string()],
+                    TreeItem {
+                        class: "tree-item",
+                        value: "src",
+                        div { class: "tree-label px-2", "src" }
+                        TreeGroup {
+                            class: "pl-4",
+                            TreeItem {
+                                class: "tree-item",
+                                value: "views",
+                                div { class: "tree-label px-2", "views" }
+                                TreeGroup {
+                                    class: "pl-4",
+                                    TreeItem { class: "tree-item", value: "showcase.rs", div { class: "tree-label px-2", "showcase.rs" } }
+                                }
+                            }
+                            TreeItem { class: "tree-item", value: "main.rs", div { class: "tree-label px-2", "main.rs" } }
+                        }
+                    }
+                    TreeItem {
+                        class: "tree-item",
+                        value: "dependencies",
+                        expandable: true,
+                        loading: dependencies_loading(),
+                        onexpand: move |_| async move {
+                            if dependencies().is_some() || dependencies_loading() {
+                                return;
+                            }
+                            dependencies_loading.set(true);
+                            // Pretend the dependencies come from a server
+                            let mut eval = document::eval("setTimeout(() => dioxus.send(true), 500)");
+                            let _ = eval.recv::<bool>().await;
+                            dependencies.set(Some(vec!["dioxus", "lucide-dioxus", "meek-aria"]));
+                            dependencies_loading.set(false);
+                        },
+                        div { class: "tree-label px-2", "dependencies" }
+                        TreeGroup {
+                            class: "pl-4",
+                            match dependencies() {
+                                Some(dependencies) => rsx! {
+                                    for dependency in dependencies {
+                                        TreeItem {
+                                            key: "{dependency}",
+                                            class: "tree-item",
+                                            value: dependency,
+                                            div { class: "tree-label px-2", "{dependency}" }
+                                        }
+                                    }
+                                },
+                                None => rsx! { div { class: "px-2 text-sm text-gray-500", "Loading…" } },
+                            }
+                        }
+                    }
+                    TreeItem { class: "tree-item", value: "Cargo.toml", div { class: "tree-label px-2", "Cargo.toml" } }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Treegrid" }
+                Treegrid {
+                    class: "text-left",
+                    aria_label: "Dependencies",
+                    focus: "cell",
+                    thead {
+                        tr {
+                            th { class: "px-2", "Crate" }
+                            th { class: "px-2", "Version" }
+                            th { class: "px-2", "License" }
+                        }
+                    }
+                    tbody {
+                        TreegridRow {
+                            class: "treegrid-row",
+                            value: "dioxus",
+                            rows: rsx! {
+                                TreegridRow {
+                                    class: "treegrid-row",
+                                    value: "dioxus-core",
+                                    TreegridCell { class: "px-2 pl-6", "dioxus-core" }
+                                    TreegridCell { class: "px-2", "0.6.1" }
+                                    TreegridCell { class: "px-2", "MIT OR Apache-2.0" }
+                                }
+                                TreegridRow {
+                                    class: "treegrid-row",
+                                    value: "dioxus-html",
+                                    TreegridCell { class: "px-2 pl-6", "dioxus-html" }
+                                    TreegridCell { class: "px-2", "0.6.1" }
+                                    TreegridCell { class: "px-2", "MIT OR Apache-2.0" }
+                                }
+                            },
+                            TreegridCell { class: "px-2", "dioxus" }
+                            TreegridCell { class: "px-2", "0.6.1" }
+                            TreegridCell { class: "px-2", "MIT OR Apache-2.0" }
+                        }
+                        TreegridRow {
+                            class: "treegrid-row",
+                            value: "strum",
+                            TreegridCell { class: "px-2", "strum" }
+                            TreegridCell { class: "px-2", "0.26.3" }
+                            TreegridCell { class: "px-2", "MIT" }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Grid" }
+                Grid {
+                    class: "text-left",
+                    aria_label: "Users",
+                    selection: "multiple",
+                    row_count: 4,
+                    thead {
+                        GridRow {
+                            class: "grid-row",
+                            index: 1,
+                            GridColumnHeader {
+                                class: "px-2",
+                                sort: if users_ascending() { GridSort::Ascending } else { GridSort::Descending },
+                                button {
+                                    tabindex: -1,
+                                    onclick: move |_| users_ascending.toggle(),
+                                    if users_ascending() { "Name ▲" } else { "Name ▼" }
+                                }
+                            }
+                            GridColumnHeader { class: "px-2", "Email" }
+                        }
+                    }
+                    tbody {
+                        for (index, (name, email)) in {
+                            let mut users = [("Ada", "ada@example.com"), ("Grace", "grace@example.com"), ("Linus", "linus@example.com")];
+                            if !users_ascending() {
+                                users.reverse();
+                            }
+                            users
+                        }.into_iter().enumerate() {
+                            GridRow {
+                                key: "{name}",
+                                class: "grid-row",
+                                value: name,
+                                index: index + 2,
+                                GridCell { class: "px-2", "{name}" }
+                                GridCell { class: "px-2", "{email}" }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Toolbar" }
+                Toolbar {
+                    class: "inline-flex items-center gap-1 p-1 rounded border",
+                    aria_label: "Formatting",
+                    ToolbarToggleGroup {
+                        class: "flex gap-1",
+                        r#type: "multiple",
+                        aria_label: "Text formatting",
+                        ToolbarToggleItem { class: "p-1", value: "bold", aria_label: "Bold", Bold { class: "w-4 h-4" } }
+                        ToolbarToggleItem { class: "p-1", value: "italic", aria_label: "Italic", Italic { class: "w-4 h-4" } }
+                        ToolbarToggleItem { class: "p-1", value: "underline", aria_label: "Underline", Underline { class: "w-4 h-4" } }
+                    }
+                    ToolbarSeparator { class: "w-px h-5 bg-gray-500" }
+                    ToolbarToggleGroup {
+                        class: "flex gap-1",
+                        default: vec!["left".to_string()],
+                        aria_label: "Text alignment",
+                        ToolbarToggleItem { class: "p-1", value: "left", aria_label: "Left aligned", AlignLeft { class: "w-4 h-4" } }
+                        ToolbarToggleItem { class: "p-1", value: "center", aria_label: "Center aligned", AlignCenter { class: "w-4 h-4" } }
+                        ToolbarToggleItem { class: "p-1", value: "right", aria_label: "Right aligned", AlignRight { class: "w-4 h-4" } }
+                    }
+                    ToolbarSeparator { class: "w-px h-5 bg-gray-500" }
+                    ToolbarLink { class: "px-2 underline", href: "#", "Edited 2 hours ago" }
+                    ToolbarButton { class: "px-2 rounded bg-rose-500", "Share" }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Toggle Group" }
+                ToggleGroup {
+                    class: "inline-flex gap-1",
+                    aria_label: "Text alignment",
+                    orientation: Orientation::Horizontal,
+                    value: alignment,
+                    ToggleGroupItem { class: "p-1", value: "left", aria_label: "Left aligned", AlignLeft { class: "w-4 h-4" } }
+                    ToggleGroupItem { class: "p-1", value: "center", aria_label: "Center aligned", AlignCenter { class: "w-4 h-4" } }
+                    ToggleGroupItem { class: "p-1", value: "right", aria_label: "Right aligned", AlignRight { class: "w-4 h-4" } }
+                }
+                p { class: "mt-2", "Aligned: {alignment.read().join(\", \")}" }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Breadcrumb" }
+                Breadcrumb {
+                    max_items: 3,
+                    BreadcrumbList {
+                        class: "flex items-center gap-2",
+                        BreadcrumbItem { BreadcrumbLink { class: "underline", to: Route::Showcase {}, "Home" } }
+                        BreadcrumbSeparator {}
+                        BreadcrumbItem { BreadcrumbLink { class: "underline", href: "#docs", "Docs" } }
+                        BreadcrumbSeparator {}
+                        BreadcrumbItem { BreadcrumbLink { class: "underline", href: "#components", "Components" } }
+                        BreadcrumbSeparator {}
+                        BreadcrumbItem { BreadcrumbLink { class: "underline", href: "#navigation", "Navigation" } }
+                        BreadcrumbSeparator {}
+                        BreadcrumbItem { BreadcrumbPage { "Breadcrumb" } }
+                    }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Carousel" }
+                Carousel {
+                    aria_label: "Featured components",
+                    autoplay: true,
+                    class: "flex flex-col gap-2 w-64",
+                    div {
+                        class: "flex items-center gap-2",
+                        CarouselRotationControl {
+                            class: "carousel-rotation",
+                            Pause { class: "pause" }
+                            Play { class: "play" }
+                        }
+                        CarouselPrevious { ChevronLeft {} }
+                        CarouselNext { ChevronRight {} }
+                    }
+                    CarouselViewport {
+                        class: "border rounded p-4 h-24",
+                        CarouselSlide { "Accordian" }
+                        CarouselSlide { "Dialog" }
+                        CarouselSlide { "Tabs" }
+                        CarouselSlide { "Tooltip" }
+                    }
+                }
+                Carousel {
+                    aria_label: "Release notes",
+                    class: "flex flex-col gap-2 w-64 mt-4",
+                    CarouselViewport {
+                        class: "border rounded p-4 h-24",
+                        CarouselSlide { "Listbox and tree view" }
+                        CarouselSlide { "Grid and tree grid" }
+                        CarouselSlide { "Toolbar and toggle group" }
+                    }
+                    CarouselPicker { class: "flex justify-center gap-2" }
+                }
+            }
+            div {
+                class: "p-4",
+                h2 { class: "text-xl font-bold mb-2", "Collapsible" }
+                Collapsible {
+                    class: "w-64",
+                    CollapsibleTrigger { class: "underline", "Show installation steps" }
+                    CollapsibleContent {
+                        class: "collapsible-content",
+                        ol {
+                            class: "list-decimal pl-4",
+                            li { "Add meek-aria to Cargo.toml" }
+                            li { "Import the primitives" }
+                            li { "Style them with data attributes" }
+                        }
+                    }
+                }
+            }
             
             Portal {}
         }